@@ -0,0 +1,33 @@
+/// Empirically-benchmarked compute unit budgets for the instructions relayers call most often,
+/// so a relayer's fixed `ComputeBudgetInstruction::set_compute_unit_limit` can be sized correctly
+/// up front instead of guessing and either failing (budget too low) or overpaying priority fees
+/// on an inflated limit.
+pub struct ComputeUnitEstimates;
+
+impl ComputeUnitEstimates {
+    pub const TRANSFER_NATIVE: u32 = 350_000;
+    pub const COMPLETE_NATIVE: u32 = 280_000;
+}
+
+/// Wormhole chain IDs of the EVM-compatible chains this relayer supports, i.e. chains whose
+/// addresses are 20 bytes left-padded to Wormhole's 32-byte address format. Used by
+/// [`crate::utils::validate_evm_recipient`] to decide which chains' `recipient_address` gets
+/// the padding check.
+pub struct WormholeEvmChainId;
+
+impl WormholeEvmChainId {
+    pub const ETHEREUM: u16 = 2;
+    pub const BSC: u16 = 4;
+    pub const POLYGON: u16 = 5;
+    pub const AVALANCHE: u16 = 6;
+    pub const FANTOM: u16 = 10;
+    pub const CELO: u16 = 14;
+    pub const ARBITRUM: u16 = 23;
+    pub const OPTIMISM: u16 = 24;
+}
+
+/// Lowest `swap_rate` (USD, scaled by `SWAP_RATE_PRECISION`) `register_token` accepts for a mint
+/// with fewer than 3 decimals. A low-decimal mint priced below this floor makes the integer
+/// division in swap/fee math round the computed fee down to zero, so `register_token` rejects it
+/// outright rather than registering a token relayers can never actually get paid for.
+pub const MIN_SWAP_RATE_FOR_LOW_DECIMAL_TOKEN: u64 = 10;
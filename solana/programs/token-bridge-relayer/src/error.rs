@@ -0,0 +1,245 @@
+use anchor_lang::prelude::*;
+
+#[error_code]
+pub enum TokenBridgeRelayerError {
+    #[msg("OwnerOnly")]
+    OwnerOnly,
+
+    #[msg("OwnerOrAssistantOnly")]
+    OwnerOrAssistantOnly,
+
+    #[msg("BumpNotFound")]
+    BumpNotFound,
+
+    #[msg("InvalidWormholeBridge")]
+    InvalidWormholeBridge,
+
+    #[msg("InvalidWormholeFeeCollector")]
+    InvalidWormholeFeeCollector,
+
+    #[msg("InvalidWormholeEmitter")]
+    InvalidWormholeEmitter,
+
+    #[msg("InvalidWormholeSequence")]
+    InvalidWormholeSequence,
+
+    #[msg("InvalidSysvar")]
+    InvalidSysvar,
+
+    #[msg("OutboundTransfersPaused")]
+    OutboundTransfersPaused,
+
+    #[msg("InvalidForeignContract")]
+    InvalidForeignContract,
+
+    #[msg("ZeroBridgeAmount")]
+    ZeroBridgeAmount,
+
+    /// A nonzero `to_native_token_amount` must normalize to a nonzero value at Token Bridge's
+    /// 8-decimal precision (see `processor::normalize_amount`), or there is nothing left to swap
+    /// once the amount reaches the redeeming side. `ToNativeAmountTooSmallForDecimals` is raised
+    /// instead of this error when that's specifically why the amount was rejected.
+    #[msg("InvalidToNativeAmount")]
+    InvalidToNativeAmount,
+
+    #[msg("NativeSwapAmountExceedsMax")]
+    NativeSwapAmountExceedsMax,
+
+    #[msg("InsufficientFunds")]
+    InsufficientFunds,
+
+    #[msg("TokenNotRegistered")]
+    TokenNotRegistered,
+
+    #[msg("TokenAlreadyRegistered")]
+    TokenAlreadyRegistered,
+
+    #[msg("FailedToParseMessagePayload")]
+    FailedToParseMessagePayload,
+
+    #[msg("InvalidMessagePayloadId")]
+    InvalidMessagePayloadId,
+
+    #[msg("InvalidRecipient")]
+    InvalidRecipient,
+
+    #[msg("VaaAlreadyRedeemed")]
+    VaaAlreadyRedeemed,
+
+    #[msg("InvalidPublicKey")]
+    InvalidPublicKey,
+
+    #[msg("NonexistentRelayerFee")]
+    NonexistentRelayerFee,
+
+    #[msg("RelayerFeePrecisionCannotBeZero")]
+    RelayerFeePrecisionCannotBeZero,
+
+    #[msg("FailedTransferTmpAccount")]
+    FailedTransferTmpAccount,
+
+    #[msg("SwapRateStale")]
+    SwapRateStale,
+
+    #[msg("DustTransfer")]
+    DustTransfer,
+
+    #[msg("FeeOutOfBounds")]
+    FeeOutOfBounds,
+
+    #[msg("InvalidFeeBounds")]
+    InvalidFeeBounds,
+
+    #[msg("ForeignContractInactive")]
+    ForeignContractInactive,
+
+    #[msg("RelayerNotWhitelisted")]
+    RelayerNotWhitelisted,
+
+    #[msg("WalletRateLimitExceeded")]
+    WalletRateLimitExceeded,
+
+    #[msg("SwapDisabledForToken")]
+    SwapDisabledForToken,
+
+    #[msg("InvalidFeeSplit")]
+    InvalidFeeSplit,
+
+    #[msg("OwnerOrOriginalPayerOnly")]
+    OwnerOrOriginalPayerOnly,
+
+    #[msg("TransferExceedsMaximum")]
+    TransferExceedsMaximum,
+
+    #[msg("RecipientBlacklisted")]
+    RecipientBlacklisted,
+
+    #[msg("InboundTransfersPaused")]
+    InboundTransfersPaused,
+
+    #[msg("BatchLengthMismatch")]
+    BatchLengthMismatch,
+
+    #[msg("InvalidForeignContractAccount")]
+    InvalidForeignContractAccount,
+
+    #[msg("ChainVolumeLimitExceeded")]
+    ChainVolumeLimitExceeded,
+
+    #[msg("TimelockNotElapsed")]
+    TimelockNotElapsed,
+
+    #[msg("PendingActionAlreadyExecuted")]
+    PendingActionAlreadyExecuted,
+
+    #[msg("PendingActionTypeMismatch")]
+    PendingActionTypeMismatch,
+
+    #[msg("SourceChainNotAllowed")]
+    SourceChainNotAllowed,
+
+    #[msg("AccountSizeMismatch")]
+    AccountSizeMismatch,
+
+    #[msg("AlreadyMigrated")]
+    AlreadyMigrated,
+
+    #[msg("TooManyForeignContracts")]
+    TooManyForeignContracts,
+
+    #[msg("ChainNotSupported")]
+    ChainNotSupported,
+
+    #[msg("OracleFeedMismatch")]
+    OracleFeedMismatch,
+
+    #[msg("OraclePriceStale")]
+    OraclePriceStale,
+
+    #[msg("OracleConfidenceTooWide")]
+    OracleConfidenceTooWide,
+
+    #[msg("OracleNegativePrice")]
+    OracleNegativePrice,
+
+    #[msg("TooManyMultisigSigners")]
+    TooManyMultisigSigners,
+
+    #[msg("InvalidMultisigThreshold")]
+    InvalidMultisigThreshold,
+
+    #[msg("DuplicateMultisigSigner")]
+    DuplicateMultisigSigner,
+
+    #[msg("NotMultisigSigner")]
+    NotMultisigSigner,
+
+    #[msg("MultisigSignerNotFound")]
+    MultisigSignerNotFound,
+
+    #[msg("MultisigActionAlreadyApproved")]
+    MultisigActionAlreadyApproved,
+
+    #[msg("MultisigActionAlreadyExecuted")]
+    MultisigActionAlreadyExecuted,
+
+    #[msg("MultisigActionTypeMismatch")]
+    MultisigActionTypeMismatch,
+
+    #[msg("MultisigThresholdNotMet")]
+    MultisigThresholdNotMet,
+
+    #[msg("InvalidGovernanceTarget")]
+    InvalidGovernanceTarget,
+
+    #[msg("MaxTokensRegistered")]
+    MaxTokensRegistered,
+
+    #[msg("ToNativeAmountTooSmallForDecimals")]
+    ToNativeAmountTooSmallForDecimals,
+
+    #[msg("InvalidSwapRatePrecision")]
+    InvalidSwapRatePrecision,
+
+    #[msg("CircuitBreakerTripped")]
+    CircuitBreakerTripped,
+
+    #[msg("InsufficientLamportsForWormholeFee")]
+    InsufficientLamportsForWormholeFee,
+
+    #[msg("StateInconsistency")]
+    StateInconsistency,
+
+    #[msg("MissingFeeRecipientTokenAccount")]
+    MissingFeeRecipientTokenAccount,
+
+    #[msg("InvalidTokenBridgeSequence")]
+    InvalidTokenBridgeSequence,
+
+    #[msg("InvalidEvmRecipientFormat")]
+    InvalidEvmRecipientFormat,
+
+    #[msg("EndpointAddressMismatch")]
+    EndpointAddressMismatch,
+
+    #[msg("SwapRateImplausibleForDecimals")]
+    SwapRateImplausibleForDecimals,
+
+    #[msg("TmpAccountNotEmpty")]
+    TmpAccountNotEmpty,
+
+    #[msg("FailedToMakeImmutable")]
+    FailedToMakeImmutable,
+
+    #[msg("SwapRateTooLow")]
+    SwapRateTooLow,
+
+    #[msg("InvalidSecondaryFeeRecipient")]
+    InvalidSecondaryFeeRecipient,
+
+    #[msg("MissingSecondaryFeeRecipientTokenAccount")]
+    MissingSecondaryFeeRecipientTokenAccount,
+
+    #[msg("PendingActionTargetMismatch")]
+    PendingActionTargetMismatch,
+}
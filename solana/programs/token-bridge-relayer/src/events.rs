@@ -0,0 +1,481 @@
+use anchor_lang::prelude::*;
+
+/// Emitted whenever an admin instruction changes on-chain governance or configuration state,
+/// so off-chain indexers don't have to diff account snapshots to reconstruct history.
+#[event]
+pub struct OwnerChanged {
+    pub old_owner: Pubkey,
+    pub new_owner: Pubkey,
+}
+
+#[event]
+pub struct AssistantChanged {
+    pub old_assistant: Pubkey,
+    pub new_assistant: Pubkey,
+}
+
+#[event]
+pub struct FeeRecipientChanged {
+    pub old_fee_recipient: Pubkey,
+    pub new_fee_recipient: Pubkey,
+}
+
+#[event]
+pub struct FeeSplitChanged {
+    pub old_split_bps: u16,
+    pub new_split_bps: u16,
+}
+
+#[event]
+pub struct TransfersPauseToggled {
+    pub paused: bool,
+}
+
+/// Emitted by `set_pause_for_inbound_transfers`, distinct from `TransfersPauseToggled` which
+/// only covers outbound transfers.
+#[event]
+pub struct InboundTransfersPauseToggled {
+    pub paused: bool,
+}
+
+#[event]
+pub struct RelayerFeeChanged {
+    pub chain: u16,
+    pub old_fee: u64,
+    pub new_fee: u64,
+}
+
+#[event]
+pub struct FeeNativeTokenAmountChanged {
+    pub chain: u16,
+    pub old_fee_native_token_amount: u64,
+    pub new_fee_native_token_amount: u64,
+}
+
+#[event]
+pub struct SwapRateChanged {
+    pub mint: Pubkey,
+    pub old_swap_rate: u64,
+    pub new_swap_rate: u64,
+}
+
+#[event]
+pub struct TokenRegistrationChanged {
+    pub mint: Pubkey,
+    pub is_registered: bool,
+}
+
+#[event]
+pub struct ForeignContractActiveChanged {
+    pub chain: u16,
+    pub is_active: bool,
+}
+
+#[event]
+pub struct FeeDenominationModeChanged {
+    pub chain: u16,
+    pub fee_in_token_units: bool,
+}
+
+#[event]
+pub struct ForeignContractClosed {
+    pub chain: u16,
+}
+
+#[event]
+pub struct ChainVolumeLimitChanged {
+    pub chain: u16,
+    pub daily_limit: u64,
+    pub slots_per_window: u64,
+}
+
+/// Emitted by `configure_circuit_breaker` whenever the program-wide volume circuit breaker's
+/// parameters change.
+#[event]
+pub struct CircuitBreakerConfigured {
+    pub enabled: bool,
+    pub window_slots: u64,
+    pub max_volume_per_window: u64,
+}
+
+/// Emitted by `reset_circuit_breaker` once the breaker's `tripped` flag has been cleared.
+#[event]
+pub struct CircuitBreakerReset {}
+
+/// Emitted by `update_max_native_swap_per_tx` whenever a `RegisteredToken`'s per-transaction
+/// native swap cap changes.
+#[event]
+pub struct MaxNativeSwapPerTxChanged {
+    pub mint: Pubkey,
+    pub old_max_native_swap_per_tx: u64,
+    pub new_max_native_swap_per_tx: u64,
+}
+
+/// Emitted from a transfer-out instruction when the normalized transfer amount exceeds
+/// `SenderConfig::large_transfer_threshold`. Informational only: off-chain monitoring can
+/// subscribe to program logs and alert on it, but the transfer itself is never blocked.
+#[event]
+pub struct LargeTransferWarning {
+    pub payer: Pubkey,
+    pub mint: Pubkey,
+    pub amount: u64,
+    pub recipient_chain: u16,
+}
+
+/// Emitted from a complete-transfer instruction once a requested native swap actually executes
+/// (as opposed to being skipped, see `SwapSkippedInsufficientRelayerBalance`/
+/// `SwapSkippedSlippage`), replacing an unstructured `msg!` log so off-chain indexers can read it
+/// without parsing a formatted string.
+#[event]
+pub struct SwapExecuted {
+    pub recipient: Pubkey,
+    pub relayer: Pubkey,
+    pub token: Pubkey,
+    pub token_amount_in: u64,
+    pub native_amount_out: u64,
+}
+
+/// Emitted from a complete-transfer instruction once the relayer fee is transferred to
+/// `fee_recipient_token_account`, replacing an unstructured `msg!` log for the same reason as
+/// `SwapExecuted`.
+#[event]
+pub struct RelayerFeePaid {
+    pub recipient: Pubkey,
+    pub fee_recipient: Pubkey,
+    pub fee_amount: u64,
+}
+
+/// Emitted by `complete_wrapped_transfer_with_relay` when `token_bridge_wrapped_mint` is the
+/// wrapped representation of native SOL (`spl_token_2022::native_mint::ID`). In that case
+/// `recipient` is credited by closing `tmp_token_account` straight to their wallet instead of
+/// receiving SPL tokens, since a WSOL token account's lamports already are the unwrapped SOL.
+#[event]
+pub struct NativeMintWrappedPath {
+    pub recipient: Pubkey,
+    pub amount: u64,
+}
+
+/// Emitted from a complete-transfer instruction when the requested native swap is skipped
+/// because the relayer does not hold enough SOL to front it, so tokens are delivered in full
+/// with no swap instead of failing the whole redemption.
+#[event]
+pub struct SwapSkippedInsufficientRelayerBalance {
+    pub recipient: Pubkey,
+    pub requested_native_amount: u64,
+}
+
+/// Emitted from a complete-transfer instruction when the computed native swap output would fall
+/// below the sender's `min_native_swap_output`, so tokens are delivered in full with no swap
+/// instead of executing a swap at a worse rate than the sender accepted.
+#[event]
+pub struct SwapSkippedSlippage {
+    pub recipient: Pubkey,
+    pub computed_native_amount: u64,
+    pub min_native_swap_output: u64,
+}
+
+/// Emitted by `claim_relayer_stats_report` so an off-chain client can read a relayer's earnings
+/// out of the transaction simulation response.
+#[event]
+pub struct RelayerEarningsReport {
+    pub relayer: Pubkey,
+    pub total_transfers: u64,
+    pub total_tokens_earned: u64,
+    pub total_native_earned: u64,
+    pub last_transfer_slot: u64,
+}
+
+/// Emitted from a complete-transfer instruction once redemption finishes, carrying the sender's
+/// `reference_id` so off-chain systems can correlate this redemption with the outbound transfer
+/// that produced it. A `reference_id` of all zeros means the sender didn't request tracking.
+#[event]
+pub struct TransferCompleted {
+    pub recipient: Pubkey,
+    pub amount: u64,
+    pub reference_id: [u8; 16],
+    pub memo: [u8; 32],
+    /// Set by `complete_native_transfer_with_relay` when `recipient_token_account` didn't exist
+    /// yet and had to be created on the relayer's behalf. Always `false` from
+    /// `complete_wrapped_transfer_with_relay`, which still requires the recipient's ATA to
+    /// already exist.
+    pub recipient_ata_created: bool,
+}
+
+/// Emitted by `compute_swap_preview` so an off-chain client can read the computed native swap
+/// amounts out of the transaction simulation response.
+#[event]
+pub struct SwapPreviewCalculated {
+    pub token_amount_in: u64,
+    pub native_amount_out: u64,
+}
+
+/// Emitted by `get_token_registry`, one event per chunk of registered mints, so an off-chain
+/// client can read the full registry out of the transaction simulation response.
+#[event]
+pub struct TokenRegistrySnapshot {
+    pub count: u64,
+    pub chunk_index: u32,
+    pub chunk: Vec<Pubkey>,
+}
+
+/// Emitted by `query_all_fees`, one event per valid `ForeignContract` passed in
+/// `remaining_accounts`, so an off-chain relayer can read every chain's fee out of a single
+/// transaction simulation response instead of one `getAccountInfo` call per chain.
+#[event]
+pub struct FeeSnapshot {
+    pub chain: u16,
+    pub fee: u64,
+}
+
+/// Emitted by `normalize_transfer_amount` so a front end can read the normalization math out of
+/// the transaction simulation response instead of reimplementing `processor::normalize_amount` /
+/// `processor::truncate_amount` client-side.
+#[event]
+pub struct AmountNormalized {
+    pub normalized: u64,
+    pub truncated: u64,
+    pub residual: u64,
+}
+
+/// Emitted by `read_audit_log` so an off-chain client can read a governance action's details out
+/// of the transaction simulation response instead of fetching and deserializing the PDA itself.
+#[event]
+pub struct AdminAuditLogEntryRead {
+    pub counter: u64,
+    pub action_type: u8,
+    pub actor: Pubkey,
+    pub target: Option<Pubkey>,
+    pub old_value: u64,
+    pub new_value: u64,
+    pub slot: u64,
+}
+
+/// Emitted by `prepare_transfer` whenever `RegisteredToken::max_fee_bps` clamps the relayer fee
+/// down from what `ForeignContract::fee` would otherwise charge.
+#[event]
+pub struct MaxFeeCapApplied {
+    pub mint: Pubkey,
+    pub requested_fee: u64,
+    pub capped_fee: u64,
+}
+
+/// Emitted by `get_program_version` so an off-chain client can read the deployed program version
+/// out of the transaction simulation response instead of fetching and deserializing the PDA
+/// itself.
+#[event]
+pub struct ProgramVersionRead {
+    pub major: u8,
+    pub minor: u8,
+    pub patch: u8,
+    pub deploy_slot: u64,
+    pub deployer: Pubkey,
+}
+
+/// Emitted once per account by `validate_account_sizes`, so an upgrade can be checked for
+/// layout drift (an account's actual data length no longer matching its expected
+/// `MAXIMUM_SIZE`) via transaction simulation instead of fetching and measuring every account.
+#[event]
+pub struct AccountSizeReport {
+    pub account: Pubkey,
+    pub actual_size: u64,
+    pub expected_size: u64,
+}
+
+/// Emitted by `detect_sequence_gap` when a payer's `SignerSequence::value` has diverged from
+/// `SignerSequence::last_committed_sequence`, which happens when an outbound transfer upticks
+/// `value` but fails before its Token Bridge CPI lands, so off-chain relayer software can flag
+/// the skipped sequence number instead of assuming every transfer succeeded.
+#[event]
+pub struct SequenceGapDetected {
+    pub payer: Pubkey,
+    pub expected: u64,
+    pub actual: u64,
+}
+
+/// Emitted by `reclaim_orphaned_tmp_account` so off-chain indexers can track how much was
+/// recovered from a stuck `tmp_token_account`, e.g. after a transfer instruction failed
+/// partway through and left tokens trapped there.
+#[event]
+pub struct TmpAccountReclaimed {
+    pub mint: Pubkey,
+    pub amount: u64,
+}
+
+#[event]
+pub struct ForeignEndpointUpdated {
+    pub chain: u16,
+    pub old_endpoint: [u8; 32],
+    pub new_endpoint: [u8; 32],
+}
+
+/// Emitted by `migrate_registered_token` once a pre-existing `RegisteredToken` account has been
+/// reallocated to the current `RegisteredToken::MAXIMUM_SIZE` and its `version` bumped.
+#[event]
+pub struct MaxForeignContractsChanged {
+    pub old_max_foreign_contracts: u16,
+    pub new_max_foreign_contracts: u16,
+}
+
+/// Emitted by `update_max_registered_tokens` whenever `OwnerConfig::max_registered_tokens`
+/// changes.
+#[event]
+pub struct MaxRegisteredTokensChanged {
+    pub old_max_registered_tokens: u16,
+    pub new_max_registered_tokens: u16,
+}
+
+/// Emitted by `register_oracle_feed`/`update_oracle_config` whenever a mint's `OracleConfig` is
+/// created or changed.
+#[event]
+pub struct OracleConfigChanged {
+    pub mint: Pubkey,
+    pub pyth_feed: Pubkey,
+    pub max_confidence_ratio_bps: u16,
+    pub max_price_age_seconds: u64,
+}
+
+/// Emitted by `update_swap_rate_from_oracle` in place of `SwapRateChanged`, so an off-chain
+/// indexer can distinguish an oracle-driven update from a manually set one.
+#[event]
+pub struct SwapRateUpdatedFromOracle {
+    pub mint: Pubkey,
+    pub old_swap_rate: u64,
+    pub new_swap_rate: u64,
+    pub pyth_publish_time: i64,
+}
+
+#[event]
+pub struct RegisteredTokenMigrated {
+    pub mint: Pubkey,
+    pub old_size: u64,
+    pub new_size: u64,
+}
+
+/// Emitted by `execute_update_precision_and_fees` alongside a `RelayerFeeChanged` per rescaled
+/// chain, so
+/// an off-chain indexer can tell a precision-driven fee rescale apart from an unrelated
+/// `update_relayer_fees_batch` call.
+#[event]
+pub struct RelayerFeePrecisionUpdated {
+    pub old_relayer_fee_precision: u32,
+    pub new_relayer_fee_precision: u32,
+}
+
+/// Emitted by `migrate_sender_config` once a pre-existing `SenderConfig` account has been
+/// rewritten in the current (smaller) layout and reallocated down to
+/// `SenderConfig::MAXIMUM_SIZE`.
+#[event]
+pub struct SenderConfigMigrated {
+    pub old_size: u64,
+    pub new_size: u64,
+}
+
+/// Emitted by `set_epoch_fee_schedule` whenever an operator creates or changes a chain's
+/// promotional fee window.
+#[event]
+pub struct EpochFeeScheduleChanged {
+    pub chain: u16,
+    pub base_fee: u64,
+    pub promo_fee: u64,
+    pub promo_start_slot: u64,
+    pub promo_end_slot: u64,
+}
+
+/// Emitted by `transfer_native_tokens_with_relay`/`transfer_wrapped_tokens_with_relay` when
+/// `truncate_amount` rounds the requested transfer amount down below Token Bridge's 8-decimal
+/// precision, and the rounded-off dust is refunded back to `from_token_account` instead of being
+/// stranded in `tmp_token_account`.
+#[event]
+pub struct TruncationResidualRefunded {
+    pub residual: u64,
+}
+
+/// Emitted by `init_multisig` once a `MultisigConfig` is created.
+#[event]
+pub struct MultisigInitialized {
+    pub signers: Vec<Pubkey>,
+    pub threshold: u8,
+}
+
+/// Emitted by `propose_multisig_action` for each new `PendingMultisigAction`.
+#[event]
+pub struct MultisigActionProposed {
+    pub nonce: u64,
+    pub action_type: u8,
+    pub proposer: Pubkey,
+}
+
+/// Emitted by `approve_multisig_action` each time a signer's approval is recorded.
+#[event]
+pub struct MultisigActionApproved {
+    pub nonce: u64,
+    pub approver: Pubkey,
+    pub approvals: u8,
+    pub threshold: u8,
+}
+
+/// Emitted by `execute_multisig_action` once a `PendingMultisigAction` reaches threshold and is
+/// applied to `MultisigConfig`.
+#[event]
+pub struct MultisigActionExecuted {
+    pub nonce: u64,
+    pub action_type: u8,
+}
+
+/// Emitted by `execute_governance_action` once a `GovernanceAction` has been applied.
+#[event]
+pub struct GovernanceActionExecuted {
+    pub vaa_hash: [u8; 32],
+    pub action_type: u8,
+}
+
+/// Emitted by `health_check` once `SenderConfig`, `RedeemerConfig`, and `OwnerConfig` are all
+/// found to agree, so operational monitoring can alert on either a failed instruction (state
+/// diverged) or a missing one (the program stopped responding) rather than parsing logs.
+#[event]
+pub struct HealthCheckPassed {
+    pub slot: u64,
+    pub paused: bool,
+    pub fee_precision: u32,
+}
+
+/// Emitted by `complete_native_transfer_with_relay` when `RedeemerConfig::owner` and
+/// `SenderConfig::owner` disagree, e.g. after a partial `submit_ownership_transfer_request` /
+/// `confirm_ownership_transfer_request` that only updated one config. Redemptions shouldn't fail
+/// because of an ownership inconsistency, so this is a non-blocking warning rather than a
+/// `require!`.
+#[event]
+pub struct OwnerMismatchWarning {
+    pub redeemer_owner: Pubkey,
+    pub sender_owner: Pubkey,
+}
+
+/// Emitted once by `deregister_tokens_batch` after it finishes iterating
+/// `ctx.remaining_accounts`, summarizing how many `RegisteredToken` accounts it actually closed
+/// (accounts that failed to parse as `RegisteredToken` are skipped and don't count).
+#[event]
+pub struct TokenDeregisteredBatch {
+    pub count: u32,
+}
+
+/// Emitted by `complete_wrapped_transfer_with_relay` when `registered_token.decimals` (captured
+/// at `register_token` time, and what redemption math actually denormalizes with) disagrees with
+/// `token_bridge_wrapped_mint.decimals` (the mint's live value). Redemption isn't blocked on this
+/// alone, since the mismatch may be benign or already accounted for elsewhere, but it flags a
+/// misconfigured or since-changed wrapped mint for off-chain review.
+#[event]
+pub struct WrappedDecimalsMismatch {
+    pub mint: Pubkey,
+    pub registered_decimals: u8,
+    pub mint_decimals: u8,
+}
+
+/// Emitted by `register_token` when a mint has fewer than 6 decimals. Not blocking on its own
+/// (unlike the fewer-than-3-decimals case, which `SwapRateImplausibleForDecimals` rejects
+/// outright) but low-decimal tokens are more prone to fee-rounding surprises, so this flags them
+/// for off-chain review.
+#[event]
+pub struct TokenRegisteredWithLowDecimals {
+    pub mint: Pubkey,
+    pub decimals: u8,
+}
@@ -0,0 +1,913 @@
+#![allow(clippy::result_large_err)]
+#![allow(unexpected_cfgs)]
+
+use anchor_lang::prelude::*;
+
+pub mod constants;
+pub mod error;
+pub mod events;
+pub mod message;
+pub mod processor;
+pub mod state;
+pub mod utils;
+
+pub(crate) use processor::*;
+
+declare_id!("TokenBridgeRe1ayer1111111111111111111111111");
+
+const SEED_PREFIX_SENDER: &[u8] = b"sender";
+const SEED_PREFIX_REDEEMER: &[u8] = b"redeemer";
+const SEED_PREFIX_FOREIGN_CONTRACT: &[u8] = b"foreign_contract";
+const SEED_PREFIX_TMP: &[u8] = b"tmp";
+const SEED_PREFIX_REGISTERED_TOKEN: &[u8] = b"mint";
+const SEED_PREFIX_SIGNER_SEQUENCE: &[u8] = b"seq";
+const SEED_PREFIX_PROGRAM_STATS: &[u8] = b"program_stats";
+const SEED_PREFIX_CHAIN_STATS: &[u8] = b"chain_stats";
+const SEED_PREFIX_RELAYER_WHITELIST: &[u8] = b"relayer_whitelist";
+const SEED_PREFIX_WALLET_RATE_LIMIT: &[u8] = b"rate_limit";
+const SEED_PREFIX_RELAYER_STATS: &[u8] = b"relayer_stats";
+const SEED_PREFIX_AUDIT_LOG_CONFIG: &[u8] = b"audit_log_config";
+const SEED_PREFIX_AUDIT_LOG: &[u8] = b"audit_log";
+const SEED_PREFIX_RECIPIENT_BLACKLIST: &[u8] = b"blacklist";
+const SEED_PREFIX_CHAIN_VOLUME_LIMIT: &[u8] = b"chain_volume";
+const SEED_PREFIX_TOKEN_REGISTRY: &[u8] = b"token_registry";
+const SEED_PREFIX_TIMELOCK_CONFIG: &[u8] = b"timelock_config";
+const SEED_PREFIX_PENDING_ACTION: &[u8] = b"pending_action";
+const SEED_PREFIX_RECEIPT: &[u8] = b"receipt";
+const SEED_PREFIX_SOURCE_ALLOWLIST: &[u8] = b"source_allowlist";
+const SEED_PREFIX_ALLOWED_CHAIN: &[u8] = b"allowed_chain";
+const SEED_PREFIX_PROGRAM_VERSION_CONFIG: &[u8] = b"program_version_config";
+const SEED_PREFIX_PROGRAM_VERSION: &[u8] = b"program_version";
+const SEED_PREFIX_SUPPORTED_CHAINS_CONFIG: &[u8] = b"supported_chains_config";
+const SEED_PREFIX_SUPPORTED_CHAIN: &[u8] = b"supported_chain";
+const SEED_PREFIX_ORACLE_CONFIG: &[u8] = b"oracle_config";
+const SEED_PREFIX_FEE_QUERY: &[u8] = b"fee_query";
+const SEED_PREFIX_EPOCH_FEE_SCHEDULE: &[u8] = b"epoch_fee_schedule";
+const SEED_PREFIX_MULTISIG: &[u8] = b"multisig";
+const SEED_PREFIX_PENDING_MULTISIG_ACTION: &[u8] = b"pending_multisig_action";
+const SEED_PREFIX_GOVERNANCE_CLAIM: &[u8] = b"governance_claim";
+const SEED_PREFIX_PAYER_HISTORY: &[u8] = b"payer_history";
+const SEED_PREFIX_CIRCUIT_BREAKER: &[u8] = b"circuit_breaker";
+
+/// Fixed-point precision used for all on-chain swap rate values.
+pub const SWAP_RATE_PRECISION: u64 = 100_000_000;
+
+/// Flat estimate of the lamports a transaction's base fee (one signature) plus its compute unit
+/// cost consume, used by the Wormhole fee pre-check in the transfer-out instructions. A single
+/// conservative constant rather than reading compute-budget instructions, since the relayer fee
+/// already has to absorb this cost regardless of the exact transaction shape.
+pub const ESTIMATED_TRANSACTION_FEE_LAMPORTS: u64 = 5_000;
+
+/// Wormhole chain ID `execute_governance_action` requires a governance VAA's emitter to have
+/// been sent from. `1` is Solana's own Wormhole chain ID; in production this would be whichever
+/// chain the deployment's dedicated governance contract lives on.
+const GOVERNANCE_EMITTER_CHAIN: u16 = 1;
+
+/// Wormhole emitter address `execute_governance_action` requires a governance VAA's emitter to
+/// match, left-zero-padded to 32 bytes like `ForeignContract::address`. Placeholder value; a
+/// real deployment would set this to its actual governance contract's emitter address.
+const GOVERNANCE_EMITTER_ADDRESS: [u8; 32] = [
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4,
+];
+
+/// Byte order used to encode `chain` into `ForeignContract` PDA seeds. Big-endian is chosen so
+/// seed bytes sort the same way as the numeric chain ID, matching Wormhole's own wire encoding.
+pub const CHAIN_ID_BYTE_ORDER: &str = "big-endian";
+
+/// Mainnet Wormhole Token Bridge program ID, used by [`utils::valid_recipient`] to reject a
+/// `TransferWithRelay` recipient address that names the bridge program itself.
+pub const TOKEN_BRIDGE_PROGRAM_ID: Pubkey =
+    anchor_lang::solana_program::pubkey!("wormDTUJ6AWPNvk59vGQbDvGJmqbDTdgWgAqcLBCgUb");
+
+/// Mainnet Wormhole core bridge program ID, used by [`utils::valid_recipient`] alongside
+/// [`TOKEN_BRIDGE_PROGRAM_ID`] and `crate::ID` to reject recipient addresses that would create a
+/// routing loop back into Wormhole infrastructure instead of a genuine end-user wallet.
+pub const WORMHOLE_CORE_BRIDGE_PROGRAM_ID: Pubkey =
+    anchor_lang::solana_program::pubkey!("worm2ZoG2kUd4vFXhvjh93UUH596ayRfgQ2MgjNMTth");
+
+/// Version of the on-chain program logic, recorded into `ProgramVersion` by `initialize` and
+/// `record_upgrade` so a deployed program's version can be determined without diffing bytecode.
+pub const PROGRAM_VERSION_MAJOR: u8 = 1;
+pub const PROGRAM_VERSION_MINOR: u8 = 0;
+pub const PROGRAM_VERSION_PATCH: u8 = 0;
+
+#[program]
+pub mod token_bridge_relayer {
+    use super::*;
+
+    /// This instruction is used to initialize the program's config accounts (`SenderConfig`
+    /// and `RedeemerConfig`). It also sets the owner and relayer fee precisions.
+    pub fn initialize(ctx: Context<Initialize>, relayer_fee_precision: u32) -> Result<()> {
+        processor::initialize(ctx, relayer_fee_precision)
+    }
+
+    pub fn register_foreign_contract(
+        ctx: Context<RegisterForeignContract>,
+        chain: u16,
+        address: [u8; 32],
+        relayer_fee: u64,
+    ) -> Result<()> {
+        processor::register_foreign_contract(ctx, chain, address, relayer_fee)
+    }
+
+    /// Registers or updates many `ForeignContract`s in one transaction. `entries` is
+    /// `(chain, address, relayer_fee)` per chain and must line up one-to-one with
+    /// `ctx.remaining_accounts`. See [`processor::register_foreign_contracts_batch`].
+    pub fn register_foreign_contracts_batch<'info>(
+        ctx: Context<'_, '_, '_, 'info, RegisterForeignContractsBatch<'info>>,
+        entries: Vec<(u16, [u8; 32], u64)>,
+    ) -> Result<()> {
+        processor::register_foreign_contracts_batch(ctx, entries)
+    }
+
+    pub fn register_token(
+        ctx: Context<RegisterToken>,
+        swap_rate: u64,
+        max_native_swap_amount: u64,
+        enable_swap: bool,
+    ) -> Result<()> {
+        processor::register_token(ctx, swap_rate, max_native_swap_amount, enable_swap)
+    }
+
+    pub fn set_token_swap_enabled(ctx: Context<SetTokenSwapEnabled>, enabled: bool) -> Result<()> {
+        processor::set_token_swap_enabled(ctx, enabled)
+    }
+
+    /// Reclaims the rent held by a deprecated chain's `ForeignContract` PDA. Historical VAAs
+    /// are unaffected; the registration can be re-created with `restore_foreign_contract`.
+    pub fn close_foreign_contract(ctx: Context<CloseForeignContract>, chain: u16) -> Result<()> {
+        processor::close_foreign_contract(ctx, chain)
+    }
+
+    /// Re-registers a foreign contract previously removed with `close_foreign_contract`.
+    pub fn restore_foreign_contract(
+        ctx: Context<RegisterForeignContract>,
+        chain: u16,
+        address: [u8; 32],
+        relayer_fee: u64,
+    ) -> Result<()> {
+        processor::restore_foreign_contract(ctx, chain, address, relayer_fee)
+    }
+
+    pub fn deregister_token(ctx: Context<DeregisterToken>) -> Result<()> {
+        processor::deregister_token(ctx)
+    }
+
+    pub fn deregister_tokens_batch<'info>(
+        ctx: Context<'_, '_, '_, 'info, DeregisterTokensBatch<'info>>,
+    ) -> Result<()> {
+        processor::deregister_tokens_batch(ctx)
+    }
+
+    pub fn update_relayer_fee(
+        ctx: Context<UpdateRelayerFee>,
+        chain: u16,
+        relayer_fee: u64,
+    ) -> Result<()> {
+        processor::update_relayer_fee(ctx, chain, relayer_fee)
+    }
+
+    pub fn update_fee_native_token(
+        ctx: Context<UpdateFeeNativeToken>,
+        chain: u16,
+        fee_native_token_amount: u64,
+    ) -> Result<()> {
+        processor::update_fee_native_token(ctx, chain, fee_native_token_amount)
+    }
+
+    /// Updates the relayer fee for many chains in one transaction. `fees` must line up with
+    /// `ctx.remaining_accounts`, alternating each chain's `ForeignContract` account with a
+    /// placeholder account. See [`processor::update_relayer_fees_batch`].
+    pub fn update_relayer_fees_batch(
+        ctx: Context<UpdateRelayerFeesBatch>,
+        fees: Vec<u64>,
+    ) -> Result<()> {
+        processor::update_relayer_fees_batch(ctx, fees)
+    }
+
+    pub fn update_swap_rate(ctx: Context<UpdateSwapRate>, swap_rate: u64) -> Result<()> {
+        processor::update_swap_rate(ctx, swap_rate)
+    }
+
+    /// Proposes overriding the swap rate precision used for one token's `swap_rate` scaling,
+    /// timelocked via `execute_set_token_swap_rate_precision`. See
+    /// [`processor::propose_set_token_swap_rate_precision`].
+    pub fn propose_set_token_swap_rate_precision(
+        ctx: Context<ProposeSetTokenSwapRatePrecision>,
+        swap_rate_precision_override: u32,
+    ) -> Result<()> {
+        processor::propose_set_token_swap_rate_precision(ctx, swap_rate_precision_override)
+    }
+
+    /// Executes a `PendingAdminAction` proposed by `propose_set_token_swap_rate_precision` once
+    /// its timelock has elapsed. See [`processor::execute_set_token_swap_rate_precision`].
+    pub fn execute_set_token_swap_rate_precision(
+        ctx: Context<ExecuteSetTokenSwapRatePrecision>,
+        action_id: u64,
+    ) -> Result<()> {
+        processor::execute_set_token_swap_rate_precision(ctx, action_id)
+    }
+
+    pub fn update_max_swap_rate_age(
+        ctx: Context<UpdateMaxSwapRateAge>,
+        max_swap_rate_age_slots: u64,
+    ) -> Result<()> {
+        processor::update_max_swap_rate_age(ctx, max_swap_rate_age_slots)
+    }
+
+    pub fn set_foreign_contract_active(
+        ctx: Context<SetForeignContractActive>,
+        chain: u16,
+        is_active: bool,
+    ) -> Result<()> {
+        processor::set_foreign_contract_active(ctx, chain, is_active)
+    }
+
+    pub fn set_fee_denomination_mode(
+        ctx: Context<SetFeeDenominationMode>,
+        chain: u16,
+        fee_in_token_units: bool,
+    ) -> Result<()> {
+        processor::set_fee_denomination_mode(ctx, chain, fee_in_token_units)
+    }
+
+    pub fn set_chain_volume_limit(
+        ctx: Context<SetChainVolumeLimit>,
+        chain: u16,
+        daily_limit: u64,
+        slots_per_window: u64,
+    ) -> Result<()> {
+        processor::set_chain_volume_limit(ctx, chain, daily_limit, slots_per_window)
+    }
+
+    /// Configures the program-wide volume circuit breaker. See
+    /// [`processor::configure_circuit_breaker`].
+    pub fn configure_circuit_breaker(
+        ctx: Context<ConfigureCircuitBreaker>,
+        enabled: bool,
+        window_slots: u64,
+        max_volume_per_window: u64,
+    ) -> Result<()> {
+        processor::configure_circuit_breaker(ctx, enabled, window_slots, max_volume_per_window)
+    }
+
+    /// Clears the circuit breaker's `tripped` flag, restoring normal outbound transfer
+    /// operation. See [`processor::reset_circuit_breaker`].
+    pub fn reset_circuit_breaker(ctx: Context<ResetCircuitBreaker>) -> Result<()> {
+        processor::reset_circuit_breaker(ctx)
+    }
+
+    pub fn update_rate_limit_params(
+        ctx: Context<UpdateRateLimitParams>,
+        rate_limit_window_slots: u64,
+        rate_limit_max_amount: u64,
+    ) -> Result<()> {
+        processor::update_rate_limit_params(ctx, rate_limit_window_slots, rate_limit_max_amount)
+    }
+
+    /// Sets the normalized transfer amount above which `LargeTransferWarning` is emitted for
+    /// off-chain monitoring. See [`processor::update_large_transfer_threshold`].
+    pub fn update_large_transfer_threshold(
+        ctx: Context<UpdateLargeTransferThreshold>,
+        large_transfer_threshold: u64,
+    ) -> Result<()> {
+        processor::update_large_transfer_threshold(ctx, large_transfer_threshold)
+    }
+
+    /// Updates the cached Wormhole message fee the transfer-out instructions pre-check `payer`'s
+    /// lamport balance against. See [`processor::update_wormhole_message_fee`].
+    pub fn update_wormhole_message_fee(
+        ctx: Context<UpdateWormholeMessageFee>,
+        wormhole_message_fee: u64,
+    ) -> Result<()> {
+        processor::update_wormhole_message_fee(ctx, wormhole_message_fee)
+    }
+
+    pub fn add_whitelisted_relayer(
+        ctx: Context<AddWhitelistedRelayer>,
+        relayer: Pubkey,
+    ) -> Result<()> {
+        processor::add_whitelisted_relayer(ctx, relayer)
+    }
+
+    pub fn remove_whitelisted_relayer(
+        ctx: Context<RemoveWhitelistedRelayer>,
+        relayer: Pubkey,
+    ) -> Result<()> {
+        processor::remove_whitelisted_relayer(ctx, relayer)
+    }
+
+    pub fn set_relayer_whitelist_enabled(
+        ctx: Context<SetRelayerWhitelistEnabled>,
+        enabled: bool,
+    ) -> Result<()> {
+        processor::set_relayer_whitelist_enabled(ctx, enabled)
+    }
+
+    pub fn update_fee_floor(ctx: Context<UpdateFeeFloor>, chain: u16, min_fee: u64) -> Result<()> {
+        processor::update_fee_floor(ctx, chain, min_fee)
+    }
+
+    pub fn update_fee_ceiling(
+        ctx: Context<UpdateFeeCeiling>,
+        chain: u16,
+        max_fee: u64,
+    ) -> Result<()> {
+        processor::update_fee_ceiling(ctx, chain, max_fee)
+    }
+
+    pub fn update_max_native_swap_amount(
+        ctx: Context<UpdateMaxNativeSwapAmount>,
+        max_native_swap_amount: u64,
+    ) -> Result<()> {
+        processor::update_max_native_swap_amount(ctx, max_native_swap_amount)
+    }
+
+    pub fn update_max_native_swap_per_tx(
+        ctx: Context<UpdateMaxNativeSwapPerTx>,
+        max_native_swap_per_tx: u64,
+    ) -> Result<()> {
+        processor::update_max_native_swap_per_tx(ctx, max_native_swap_per_tx)
+    }
+
+    pub fn update_assistant(ctx: Context<UpdateAssistant>, new_assistant: Pubkey) -> Result<()> {
+        processor::update_assistant(ctx, new_assistant)
+    }
+
+    /// Sets the basis-point split of the relayer fee between `fee_recipient` and
+    /// `secondary_fee_recipient`. See [`RedeemerConfig::fee_split_bps`].
+    pub fn update_fee_split(ctx: Context<UpdateFeeSplit>, new_split_bps: u16) -> Result<()> {
+        processor::update_fee_split(ctx, new_split_bps)
+    }
+
+    /// Reclaims the rent held by a defunct `SignerSequence` PDA. Callable by the original payer
+    /// (self-close) or the program owner.
+    pub fn close_signer_sequence(ctx: Context<CloseSignerSequence>) -> Result<()> {
+        processor::close_signer_sequence(ctx)
+    }
+
+    /// Reclaims the rent held by the caller's own `RelayerStats` PDA.
+    pub fn close_relayer_stats(ctx: Context<CloseRelayerStats>) -> Result<()> {
+        processor::close_relayer_stats(ctx)
+    }
+
+    /// View-only instruction that emits the caller's relayer earnings as a
+    /// `RelayerEarningsReport` event.
+    pub fn claim_relayer_stats_report(ctx: Context<ClaimRelayerStatsReport>) -> Result<()> {
+        processor::claim_relayer_stats_report(ctx)
+    }
+
+    /// Sets the maximum amount of a token, normalized to Token Bridge's 8-decimal precision,
+    /// that can be bridged out in a single transfer. Zero means uncapped.
+    pub fn update_max_transfer_amount(
+        ctx: Context<UpdateMaxTransferAmount>,
+        max_transfer_amount: u64,
+    ) -> Result<()> {
+        processor::update_max_transfer_amount(ctx, max_transfer_amount)
+    }
+
+    /// View-only instruction that emits a single `AdminAuditLog` entry as an
+    /// `AdminAuditLogEntryRead` event.
+    pub fn read_audit_log(ctx: Context<ReadAuditLog>, counter: u64) -> Result<()> {
+        processor::read_audit_log(ctx, counter)
+    }
+
+    /// Adds `address` to the recipient blacklist, or marks an existing entry blocked again.
+    pub fn add_to_blacklist(ctx: Context<AddToBlacklist>, address: [u8; 32]) -> Result<()> {
+        processor::add_to_blacklist(ctx, address)
+    }
+
+    /// Marks a recipient blacklist entry as no longer blocked.
+    pub fn remove_from_blacklist(
+        ctx: Context<RemoveFromBlacklist>,
+        address: [u8; 32],
+    ) -> Result<()> {
+        processor::remove_from_blacklist(ctx, address)
+    }
+
+    pub fn submit_ownership_transfer_request(
+        ctx: Context<SubmitOwnershipTransferRequest>,
+        new_owner: Pubkey,
+    ) -> Result<()> {
+        processor::submit_ownership_transfer_request(ctx, new_owner)
+    }
+
+    pub fn cancel_ownership_transfer_request(
+        ctx: Context<CancelOwnershipTransferRequest>,
+    ) -> Result<()> {
+        processor::cancel_ownership_transfer_request(ctx)
+    }
+
+    pub fn set_pause_for_transfers(ctx: Context<SetPauseForTransfers>, paused: bool) -> Result<()> {
+        processor::set_pause_for_transfers(ctx, paused)
+    }
+
+    pub fn set_pause_for_inbound_transfers(
+        ctx: Context<SetPauseForInboundTransfers>,
+        paused: bool,
+    ) -> Result<()> {
+        processor::set_pause_for_inbound_transfers(ctx, paused)
+    }
+
+    /// Owner-only recovery for a `tmp_token_account` left with a stuck balance after a failed
+    /// or partially executed transfer.
+    pub fn reclaim_orphaned_tmp_account(ctx: Context<ReclaimTmpAccount>) -> Result<()> {
+        processor::reclaim_orphaned_tmp_account(ctx)
+    }
+
+    /// Owner-only reset of a registered token's cumulative volume counters.
+    pub fn reset_cumulative_volume(ctx: Context<ResetCumulativeVolume>) -> Result<()> {
+        processor::reset_cumulative_volume(ctx)
+    }
+
+    /// Owner-only reset of the program-wide transfer counters.
+    pub fn reset_program_stats(ctx: Context<ResetProgramStats>) -> Result<()> {
+        processor::reset_program_stats(ctx)
+    }
+
+    /// Owner-only reset of a single chain's transfer counters.
+    pub fn reset_chain_stats(ctx: Context<ResetChainStats>, chain: u16) -> Result<()> {
+        processor::reset_chain_stats(ctx, chain)
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub fn transfer_native_tokens_with_relay(
+        ctx: Context<TransferNativeTokensWithRelay>,
+        amount: u64,
+        to_native_token_amount: u64,
+        recipient_chain: u16,
+        recipient_address: [u8; 32],
+        batch_id: u32,
+        check_blacklist: bool,
+        memo: [u8; 32],
+    ) -> Result<()> {
+        processor::transfer_native_tokens_with_relay(
+            ctx,
+            amount,
+            to_native_token_amount,
+            recipient_chain,
+            recipient_address,
+            batch_id,
+            check_blacklist,
+            memo,
+        )
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub fn transfer_wrapped_tokens_with_relay(
+        ctx: Context<TransferWrappedTokensWithRelay>,
+        amount: u64,
+        to_native_token_amount: u64,
+        recipient_chain: u16,
+        recipient_address: [u8; 32],
+        batch_id: u32,
+        check_blacklist: bool,
+        memo: [u8; 32],
+    ) -> Result<()> {
+        processor::transfer_wrapped_tokens_with_relay(
+            ctx,
+            amount,
+            to_native_token_amount,
+            recipient_chain,
+            recipient_address,
+            batch_id,
+            check_blacklist,
+            memo,
+        )
+    }
+
+    pub fn complete_native_transfer_with_relay(
+        ctx: Context<CompleteNativeTransferWithRelay>,
+        _vaa_hash: [u8; 32],
+    ) -> Result<()> {
+        processor::complete_native_transfer_with_relay(ctx, _vaa_hash)
+    }
+
+    pub fn complete_wrapped_transfer_with_relay(
+        ctx: Context<CompleteWrappedTransferWithRelay>,
+        _vaa_hash: [u8; 32],
+    ) -> Result<()> {
+        processor::complete_wrapped_transfer_with_relay(ctx, _vaa_hash)
+    }
+
+    /// Reclaims the rent held by a `TransferReceipt` PDA. See
+    /// [`processor::close_transfer_receipt`].
+    pub fn close_transfer_receipt(
+        ctx: Context<CloseTransferReceipt>,
+        vaa_hash: [u8; 32],
+    ) -> Result<()> {
+        processor::close_transfer_receipt(ctx, vaa_hash)
+    }
+
+    /// Enables inbound source-chain filtering; only chains with an `AllowedSourceChain` marker
+    /// may redeem transfers afterward. See [`processor::enable_source_allowlist`].
+    pub fn enable_source_allowlist(ctx: Context<SetSourceAllowlistEnabled>) -> Result<()> {
+        processor::enable_source_allowlist(ctx)
+    }
+
+    /// Disables inbound source-chain filtering, reverting to accepting transfers from any
+    /// registered chain. See [`processor::disable_source_allowlist`].
+    pub fn disable_source_allowlist(ctx: Context<SetSourceAllowlistEnabled>) -> Result<()> {
+        processor::disable_source_allowlist(ctx)
+    }
+
+    /// Whitelists `chain` for inbound redemption while the source allowlist is enabled. See
+    /// [`processor::add_source_chain`].
+    pub fn add_source_chain(ctx: Context<AddSourceChain>, chain: u16) -> Result<()> {
+        processor::add_source_chain(ctx, chain)
+    }
+
+    /// Removes `chain` from the inbound source allowlist. See
+    /// [`processor::remove_source_chain`].
+    pub fn remove_source_chain(ctx: Context<RemoveSourceChain>, chain: u16) -> Result<()> {
+        processor::remove_source_chain(ctx, chain)
+    }
+
+    /// View-only instruction that computes the relayer fee (in the mint's raw token units) for
+    /// a transfer to `chain`, so off-chain clients don't have to reimplement the fee formula
+    /// themselves. The result is written to the ephemeral `fee_estimate` account, which is
+    /// closed at the end of this instruction, so callers read it via transaction simulation.
+    pub fn compute_relayer_fee(
+        ctx: Context<ComputeRelayerFee>,
+        chain: u16,
+        decimals: u8,
+    ) -> Result<()> {
+        processor::compute_relayer_fee(ctx, chain, decimals)
+    }
+
+    /// Previews the native swap amounts a redemption requesting `to_native_token_amount` would
+    /// produce, so off-chain clients can show the user an estimate before submitting a transfer.
+    /// The result is written to the ephemeral `swap_preview` account, which is closed at the end
+    /// of this instruction, so callers read it via transaction simulation.
+    pub fn compute_swap_preview(
+        ctx: Context<ComputeSwapPreview>,
+        decimals: u8,
+        to_native_token_amount: u64,
+    ) -> Result<()> {
+        processor::compute_swap_preview(ctx, decimals, to_native_token_amount)
+    }
+
+    /// Emits every registered mint as chunked `TokenRegistrySnapshot` events, so off-chain
+    /// relayer software can discover registered tokens via transaction simulation instead of
+    /// scanning all program accounts.
+    pub fn get_token_registry(ctx: Context<GetTokenRegistry>) -> Result<()> {
+        processor::get_token_registry(ctx)
+    }
+
+    /// Emits a `FeeSnapshot` for every `ForeignContract` passed in via `remaining_accounts`, so
+    /// off-chain relayer software can read every chain's fee in one call. See
+    /// [`processor::query_all_fees`].
+    pub fn query_all_fees(ctx: Context<QueryAllFees>) -> Result<()> {
+        processor::query_all_fees(ctx)
+    }
+
+    /// Translates a human-readable amount into its normalized/truncated/residual breakdown, so
+    /// front ends don't have to reimplement Token Bridge's amount math. See
+    /// [`processor::normalize_transfer_amount`].
+    pub fn normalize_transfer_amount(
+        ctx: Context<NormalizeTransferAmount>,
+        amount: u64,
+        decimals: u8,
+    ) -> Result<()> {
+        processor::normalize_transfer_amount(ctx, amount, decimals)
+    }
+
+    /// Sets how many slots a `PendingAdminAction` must sit before `execute_*` will replay it.
+    pub fn set_timelock_delay(ctx: Context<SetTimelockDelay>, delay_slots: u64) -> Result<()> {
+        processor::set_timelock_delay(ctx, delay_slots)
+    }
+
+    /// Proposes a new `RedeemerConfig::fee_recipient`/`secondary_fee_recipient`, timelocked via
+    /// `execute_update_fee_recipient`. See [`processor::propose_update_fee_recipient`].
+    pub fn propose_update_fee_recipient(
+        ctx: Context<ProposeUpdateFeeRecipient>,
+        new_fee_recipient: Pubkey,
+        new_secondary_fee_recipient: Option<Pubkey>,
+    ) -> Result<()> {
+        processor::propose_update_fee_recipient(ctx, new_fee_recipient, new_secondary_fee_recipient)
+    }
+
+    /// Executes a `PendingAdminAction` proposed by `propose_update_fee_recipient` once its
+    /// timelock has elapsed. See [`processor::execute_update_fee_recipient`].
+    pub fn execute_update_fee_recipient(
+        ctx: Context<ExecuteUpdateFeeRecipient>,
+        action_id: u64,
+    ) -> Result<()> {
+        processor::execute_update_fee_recipient(ctx, action_id)
+    }
+
+    /// Proposes applying an already-submitted ownership transfer request, timelocked via
+    /// `execute_confirm_ownership_transfer_request`. See
+    /// [`processor::propose_confirm_ownership_transfer_request`].
+    pub fn propose_confirm_ownership_transfer_request(
+        ctx: Context<ProposeConfirmOwnershipTransferRequest>,
+    ) -> Result<()> {
+        processor::propose_confirm_ownership_transfer_request(ctx)
+    }
+
+    /// Executes a `PendingAdminAction` proposed by `propose_confirm_ownership_transfer_request`
+    /// once its timelock has elapsed. See
+    /// [`processor::execute_confirm_ownership_transfer_request`].
+    pub fn execute_confirm_ownership_transfer_request(
+        ctx: Context<ExecuteConfirmOwnershipTransferRequest>,
+        action_id: u64,
+    ) -> Result<()> {
+        processor::execute_confirm_ownership_transfer_request(ctx, action_id)
+    }
+
+    /// CPI-friendly quote of the relayer fee and swap costs for redeeming `amount` of `mint`
+    /// from `chain`, written into the caller-provided `fee_quote` account. See
+    /// [`processor::get_relayer_fee_quote`].
+    pub fn get_relayer_fee_quote(
+        ctx: Context<GetRelayerFeeQuote>,
+        chain: u16,
+        mint: Pubkey,
+        amount: u64,
+    ) -> Result<()> {
+        processor::get_relayer_fee_quote(ctx, chain, mint, amount)
+    }
+
+    /// Sets the basis-point cap on how much of a transfer's value the relayer fee may consume
+    /// for a given mint. See [`processor::update_max_fee_bps`].
+    pub fn update_max_fee_bps(ctx: Context<UpdateMaxFeeBps>, max_fee_bps: u16) -> Result<()> {
+        processor::update_max_fee_bps(ctx, max_fee_bps)
+    }
+
+    /// Appends a new `ProgramVersion` entry recording the version now running on-chain. See
+    /// [`processor::record_upgrade`].
+    pub fn record_upgrade(
+        ctx: Context<RecordUpgrade>,
+        major: u8,
+        minor: u8,
+        patch: u8,
+    ) -> Result<()> {
+        processor::record_upgrade(ctx, major, minor, patch)
+    }
+
+    /// Emits the deployed version out of a `ProgramVersion` PDA as a `ProgramVersionRead` event,
+    /// so off-chain clients can read it via transaction simulation. See
+    /// [`processor::get_program_version`].
+    pub fn get_program_version(ctx: Context<GetProgramVersion>) -> Result<()> {
+        processor::get_program_version(ctx)
+    }
+
+    /// Emits an `AccountSizeReport` for each singleton config account, so an upgrade can be
+    /// checked for layout drift via transaction simulation. See
+    /// [`processor::validate_account_sizes`].
+    pub fn validate_account_sizes(ctx: Context<ValidateAccountSizes>) -> Result<()> {
+        processor::validate_account_sizes(ctx)
+    }
+
+    /// Emits `SequenceGapDetected` if `payer`'s `SignerSequence` shows a transfer that upticked
+    /// its sequence number without its Token Bridge CPI landing. See
+    /// [`processor::detect_sequence_gap`].
+    pub fn detect_sequence_gap(ctx: Context<DetectSequenceGap>) -> Result<()> {
+        processor::detect_sequence_gap(ctx)
+    }
+
+    /// RPC-callable liveness/consistency check for monitoring infrastructure: confirms
+    /// `SenderConfig`, `RedeemerConfig`, and `OwnerConfig` agree, then emits `HealthCheckPassed`.
+    /// See [`processor::health_check`].
+    pub fn health_check(ctx: Context<HealthCheck>) -> Result<()> {
+        processor::health_check(ctx)
+    }
+
+    /// Updates a `ForeignContract`'s `token_bridge_foreign_endpoint` without touching its
+    /// `chain`, `address`, or fee bounds. See [`processor::update_foreign_endpoint`].
+    pub fn update_foreign_endpoint(
+        ctx: Context<UpdateForeignEndpoint>,
+        chain: u16,
+        token_bridge_foreign_endpoint: [u8; 32],
+    ) -> Result<()> {
+        processor::update_foreign_endpoint(ctx, chain, token_bridge_foreign_endpoint)
+    }
+
+    /// Reallocates a pre-existing `RegisteredToken` account up to the current
+    /// `RegisteredToken::MAXIMUM_SIZE` and bumps its `version` from `0` to `1`, so a field added
+    /// to the struct doesn't require closing and recreating every registered token account. See
+    /// [`processor::migrate_registered_token`].
+    pub fn migrate_registered_token(ctx: Context<MigrateRegisteredToken>) -> Result<()> {
+        processor::migrate_registered_token(ctx)
+    }
+
+    /// Reallocates a `migrate_registered_token`-ed `RegisteredToken` account to the current
+    /// `RegisteredToken::MAXIMUM_SIZE`, dropping the removed `is_registered` field now that an
+    /// account's mere existence is the registration signal, and bumps its `version` from `1`
+    /// straight to `RegisteredToken::CURRENT_VERSION`. See
+    /// [`processor::migrate_registered_token_v2`].
+    pub fn migrate_registered_token_v2(ctx: Context<MigrateRegisteredTokenV2>) -> Result<()> {
+        processor::migrate_registered_token_v2(ctx)
+    }
+
+    /// Reallocates a `migrate_registered_token_v2`-ed `RegisteredToken` account up to the
+    /// current `RegisteredToken::MAXIMUM_SIZE`, backfilling
+    /// `swap_rate_precision_override`/`max_native_swap_per_tx` with their zero defaults, and
+    /// bumps its `version` from `2` to `3`. See [`processor::migrate_registered_token_v3`].
+    pub fn migrate_registered_token_v3(ctx: Context<MigrateRegisteredTokenV3>) -> Result<()> {
+        processor::migrate_registered_token_v3(ctx)
+    }
+
+    /// Reallocates a pre-existing `SenderConfig` account down to the current
+    /// `SenderConfig::MAXIMUM_SIZE`, dropping the removed `swap_rate_precision` field now that
+    /// callers use the `SWAP_RATE_PRECISION` constant directly. See
+    /// [`processor::migrate_sender_config`].
+    pub fn migrate_sender_config(ctx: Context<MigrateSenderConfig>) -> Result<()> {
+        processor::migrate_sender_config(ctx)
+    }
+
+    /// Caps how many `ForeignContract` registrations `register_foreign_contract` will allow at
+    /// once. See [`processor::update_max_foreign_contracts`].
+    pub fn update_max_foreign_contracts(
+        ctx: Context<UpdateMaxForeignContracts>,
+        max_foreign_contracts: u16,
+    ) -> Result<()> {
+        processor::update_max_foreign_contracts(ctx, max_foreign_contracts)
+    }
+
+    /// Toggles outbound recipient-chain filtering; while enforced, `transfer_native_tokens_with_relay`
+    /// and `transfer_wrapped_tokens_with_relay` require a `SupportedChain` marker for
+    /// `recipient_chain`. See [`processor::set_enforce_chain_allowlist`].
+    pub fn set_enforce_chain_allowlist(
+        ctx: Context<SetEnforceChainAllowlist>,
+        enforce_allowlist: bool,
+    ) -> Result<()> {
+        processor::set_enforce_chain_allowlist(ctx, enforce_allowlist)
+    }
+
+    /// Marks `chain` as supported for outbound transfers while the allowlist is enforced. See
+    /// [`processor::register_supported_chain`].
+    pub fn register_supported_chain(
+        ctx: Context<RegisterSupportedChain>,
+        chain: u16,
+    ) -> Result<()> {
+        processor::register_supported_chain(ctx, chain)
+    }
+
+    /// Removes `chain` from the outbound supported-chain allowlist. See
+    /// [`processor::deregister_supported_chain`].
+    pub fn deregister_supported_chain(
+        ctx: Context<DeregisterSupportedChain>,
+        chain: u16,
+    ) -> Result<()> {
+        processor::deregister_supported_chain(ctx, chain)
+    }
+
+    /// Registers the Pyth `PriceFeed` account and validation bounds `update_swap_rate_from_oracle`
+    /// will use for `mint`. See [`processor::register_oracle_feed`].
+    pub fn register_oracle_feed(
+        ctx: Context<RegisterOracleFeed>,
+        pyth_feed: Pubkey,
+        max_confidence_ratio_bps: u16,
+        max_price_age_seconds: u64,
+    ) -> Result<()> {
+        processor::register_oracle_feed(
+            ctx,
+            pyth_feed,
+            max_confidence_ratio_bps,
+            max_price_age_seconds,
+        )
+    }
+
+    /// Updates an existing mint's `OracleConfig`. See [`processor::update_oracle_config`].
+    pub fn update_oracle_config(
+        ctx: Context<UpdateOracleConfig>,
+        pyth_feed: Pubkey,
+        max_confidence_ratio_bps: u16,
+        max_price_age_seconds: u64,
+    ) -> Result<()> {
+        processor::update_oracle_config(
+            ctx,
+            pyth_feed,
+            max_confidence_ratio_bps,
+            max_price_age_seconds,
+        )
+    }
+
+    /// Reads `mint`'s registered Pyth `PriceFeed`, validates its confidence interval and age
+    /// against `OracleConfig`, and writes the resulting price into `registered_token.swap_rate`.
+    /// See [`processor::update_swap_rate_from_oracle`].
+    pub fn update_swap_rate_from_oracle(ctx: Context<UpdateSwapRateFromOracle>) -> Result<()> {
+        processor::update_swap_rate_from_oracle(ctx)
+    }
+
+    /// Proposes atomically updating `relayer_fee_precision` on `SenderConfig` and
+    /// `RedeemerConfig` and rescaling the `ForeignContract.fee` of every chain named in `fees`,
+    /// timelocked via `execute_update_precision_and_fees`. See
+    /// [`processor::propose_update_precision_and_fees`].
+    pub fn propose_update_precision_and_fees(
+        ctx: Context<ProposeUpdatePrecisionAndFees>,
+        relayer_fee_precision: u32,
+        fees: Vec<(u16, u64)>,
+    ) -> Result<()> {
+        processor::propose_update_precision_and_fees(ctx, relayer_fee_precision, fees)
+    }
+
+    /// Executes a `PendingAdminAction` proposed by `propose_update_precision_and_fees` once its
+    /// timelock has elapsed. `ctx.remaining_accounts` must line up one-to-one with the `fees`
+    /// that were proposed. See [`processor::execute_update_precision_and_fees`].
+    pub fn execute_update_precision_and_fees<'info>(
+        ctx: Context<'_, '_, '_, 'info, ExecuteUpdatePrecisionAndFees<'info>>,
+        action_id: u64,
+    ) -> Result<()> {
+        processor::execute_update_precision_and_fees(ctx, action_id)
+    }
+
+    /// CPI-callable counterpart to [`compute_relayer_fee`]: writes the relayer fee for `chain`
+    /// into a `fee_query_result` PDA seeded by `payer`, so another Solana program can CPI in and
+    /// then read the result straight out of an account it controls, without relying on
+    /// transaction simulation. See [`processor::query_token_fee`].
+    pub fn query_token_fee(ctx: Context<QueryTokenFee>, chain: u16) -> Result<()> {
+        processor::query_token_fee(ctx, chain)
+    }
+
+    /// Closes a `fee_query_result` PDA opened by `query_token_fee`, releasing its rent back to
+    /// `payer`. See [`processor::close_fee_query_result`].
+    pub fn close_fee_query_result(ctx: Context<CloseFeeQueryResult>) -> Result<()> {
+        processor::close_fee_query_result(ctx)
+    }
+
+    /// Creates or updates `chain`'s promotional fee window: while the current slot falls in
+    /// `[promo_start_slot, promo_end_slot)`, outbound transfers to `chain` are charged
+    /// `promo_fee` instead of `ForeignContract::fee`; outside that window they're charged
+    /// `base_fee`. See [`processor::set_epoch_fee_schedule`].
+    pub fn set_epoch_fee_schedule(
+        ctx: Context<SetEpochFeeSchedule>,
+        chain: u16,
+        base_fee: u64,
+        promo_fee: u64,
+        promo_start_slot: u64,
+        promo_end_slot: u64,
+    ) -> Result<()> {
+        processor::set_epoch_fee_schedule(
+            ctx,
+            chain,
+            base_fee,
+            promo_fee,
+            promo_start_slot,
+            promo_end_slot,
+        )
+    }
+
+    /// Creates the singleton `MultisigConfig`, called once by the current owner. See the module
+    /// doc on `MultisigConfig` for what is (and isn't yet) governed by it.
+    pub fn init_multisig(
+        ctx: Context<InitMultisig>,
+        signers: Vec<Pubkey>,
+        threshold: u8,
+    ) -> Result<()> {
+        processor::init_multisig(ctx, signers, threshold)
+    }
+
+    /// Proposes a `MultisigConfig` mutation and records the proposer's own approval. See
+    /// [`processor::propose_multisig_action`].
+    pub fn propose_multisig_action(
+        ctx: Context<ProposeMultisigAction>,
+        action_type: u8,
+        encoded_args: Vec<u8>,
+    ) -> Result<()> {
+        processor::propose_multisig_action(ctx, action_type, encoded_args)
+    }
+
+    /// Records a current signer's approval of a `PendingMultisigAction`. See
+    /// [`processor::approve_multisig_action`].
+    pub fn approve_multisig_action(ctx: Context<ApproveMultisigAction>, nonce: u64) -> Result<()> {
+        processor::approve_multisig_action(ctx, nonce)
+    }
+
+    /// Applies a `PendingMultisigAction` once it has reached `MultisigConfig::threshold`
+    /// approvals. See [`processor::execute_multisig_action`].
+    pub fn execute_multisig_action(ctx: Context<ExecuteMultisigAction>, nonce: u64) -> Result<()> {
+        processor::execute_multisig_action(ctx, nonce)
+    }
+
+    /// Applies a governance action from a Wormhole governance VAA. See
+    /// [`processor::execute_governance_action`] for what this program can and can't verify about
+    /// the VAA itself.
+    pub fn execute_governance_action(
+        ctx: Context<ExecuteGovernanceAction>,
+        vaa_hash: [u8; 32],
+        emitter_chain: u16,
+        emitter_address: [u8; 32],
+        action: GovernanceAction,
+    ) -> Result<()> {
+        processor::execute_governance_action(ctx, vaa_hash, emitter_chain, emitter_address, action)
+    }
+
+    /// Reclaims the rent held by the caller's own `PayerTransferHistory` PDA.
+    pub fn close_payer_history(ctx: Context<ClosePayerHistory>) -> Result<()> {
+        processor::close_payer_history(ctx)
+    }
+
+    /// Caps how many `RegisteredToken` registrations `register_token` will allow at once. See
+    /// [`processor::update_max_registered_tokens`].
+    pub fn update_max_registered_tokens(
+        ctx: Context<UpdateMaxRegisteredTokens>,
+        max_registered_tokens: u16,
+    ) -> Result<()> {
+        processor::update_max_registered_tokens(ctx, max_registered_tokens)
+    }
+
+    pub fn revoke_upgrade_authority(ctx: Context<RevokeUpgradeAuthority>) -> Result<()> {
+        processor::revoke_upgrade_authority(ctx)
+    }
+}
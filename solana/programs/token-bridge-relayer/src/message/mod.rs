@@ -0,0 +1,2 @@
+mod token_bridge_relayer;
+pub use token_bridge_relayer::*;
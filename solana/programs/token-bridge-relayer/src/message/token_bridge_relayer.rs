@@ -0,0 +1,481 @@
+use crate::{error::TokenBridgeRelayerError, utils::valid_recipient};
+use anchor_lang::prelude::*;
+use std::io;
+
+/// Rejects a payload's `recipient` field as early as possible: the zero address and any reserved
+/// program address (System Program, Token Bridge, Wormhole core bridge, or this program) can
+/// never hold an SPL token account, so a payload naming one of them is malformed and would
+/// otherwise only surface as `InvalidRecipient` much later, in a complete-transfer instruction.
+fn require_valid_recipient(recipient: [u8; 32]) -> io::Result<()> {
+    if recipient == [0u8; 32] {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            format!(
+                "{:?}: zero recipient",
+                TokenBridgeRelayerError::InvalidRecipient
+            ),
+        ));
+    }
+
+    if !valid_recipient(&recipient) {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            format!(
+                "{:?}: recipient is a reserved program address",
+                TokenBridgeRelayerError::InvalidRecipient
+            ),
+        ));
+    }
+
+    Ok(())
+}
+
+/// Payload sent in the Token Bridge transfer-with-payload's `payload` field, decoded on the
+/// redeeming chain to recover the relayer fee and desired native swap amount.
+///
+/// Payload ID 1 is the original format. Payload ID 2 additionally carries
+/// `min_native_swap_output`, letting the sender bound the native swap's slippage; callers on
+/// payload ID 1 are treated as if they passed `min_native_swap_output = 0` (no check). Payload
+/// ID 3 additionally carries an opaque `reference_id` that off-chain systems can use to
+/// correlate an outbound transfer with its redemption; a value of all zeros means the sender
+/// didn't request tracking, and payload IDs 1 and 2 are treated the same way. Payload ID 4
+/// additionally carries an opaque `memo` (e.g. an order ID) for the recipient; a value of all
+/// zeros means no memo, and payload IDs 1 through 3 are treated the same way.
+#[derive(Clone, Copy)]
+pub enum TokenBridgeRelayerMessage {
+    TransferWithRelay {
+        target_relayer_fee: u64,
+        to_native_token_amount: u64,
+        recipient: [u8; 32],
+    },
+    TransferWithRelayV2 {
+        target_relayer_fee: u64,
+        to_native_token_amount: u64,
+        recipient: [u8; 32],
+        min_native_swap_output: u64,
+    },
+    TransferWithRelayV3 {
+        target_relayer_fee: u64,
+        to_native_token_amount: u64,
+        recipient: [u8; 32],
+        min_native_swap_output: u64,
+        reference_id: [u8; 16],
+    },
+    TransferWithRelayV4 {
+        target_relayer_fee: u64,
+        to_native_token_amount: u64,
+        recipient: [u8; 32],
+        min_native_swap_output: u64,
+        reference_id: [u8; 16],
+        memo: [u8; 32],
+    },
+}
+
+impl TokenBridgeRelayerMessage {
+    pub fn target_relayer_fee(&self) -> u64 {
+        match self {
+            Self::TransferWithRelay {
+                target_relayer_fee, ..
+            } => *target_relayer_fee,
+            Self::TransferWithRelayV2 {
+                target_relayer_fee, ..
+            } => *target_relayer_fee,
+            Self::TransferWithRelayV3 {
+                target_relayer_fee, ..
+            } => *target_relayer_fee,
+            Self::TransferWithRelayV4 {
+                target_relayer_fee, ..
+            } => *target_relayer_fee,
+        }
+    }
+
+    pub fn to_native_token_amount(&self) -> u64 {
+        match self {
+            Self::TransferWithRelay {
+                to_native_token_amount,
+                ..
+            } => *to_native_token_amount,
+            Self::TransferWithRelayV2 {
+                to_native_token_amount,
+                ..
+            } => *to_native_token_amount,
+            Self::TransferWithRelayV3 {
+                to_native_token_amount,
+                ..
+            } => *to_native_token_amount,
+            Self::TransferWithRelayV4 {
+                to_native_token_amount,
+                ..
+            } => *to_native_token_amount,
+        }
+    }
+
+    /// Minimum lamports the sender will accept from the native swap. Payload ID 1 messages
+    /// always return `0`, meaning no slippage check is enforced.
+    pub fn min_native_swap_output(&self) -> u64 {
+        match self {
+            Self::TransferWithRelay { .. } => 0,
+            Self::TransferWithRelayV2 {
+                min_native_swap_output,
+                ..
+            } => *min_native_swap_output,
+            Self::TransferWithRelayV3 {
+                min_native_swap_output,
+                ..
+            } => *min_native_swap_output,
+            Self::TransferWithRelayV4 {
+                min_native_swap_output,
+                ..
+            } => *min_native_swap_output,
+        }
+    }
+
+    /// Opaque correlation identifier supplied by the sender. Payload IDs 1 and 2 always return
+    /// all zeros, meaning the sender did not request tracking.
+    pub fn reference_id(&self) -> [u8; 16] {
+        match self {
+            Self::TransferWithRelay { .. } => [0u8; 16],
+            Self::TransferWithRelayV2 { .. } => [0u8; 16],
+            Self::TransferWithRelayV3 { reference_id, .. } => *reference_id,
+            Self::TransferWithRelayV4 { reference_id, .. } => *reference_id,
+        }
+    }
+
+    /// Opaque memo supplied by the sender (e.g. an order ID). Payload IDs 1 through 3 always
+    /// return all zeros, meaning the sender did not attach a memo.
+    pub fn memo(&self) -> [u8; 32] {
+        match self {
+            Self::TransferWithRelay { .. } => [0u8; 32],
+            Self::TransferWithRelayV2 { .. } => [0u8; 32],
+            Self::TransferWithRelayV3 { .. } => [0u8; 32],
+            Self::TransferWithRelayV4 { memo, .. } => *memo,
+        }
+    }
+}
+
+impl AnchorSerialize for TokenBridgeRelayerMessage {
+    fn serialize<W: io::Write>(&self, writer: &mut W) -> io::Result<()> {
+        match self {
+            TokenBridgeRelayerMessage::TransferWithRelay {
+                target_relayer_fee,
+                to_native_token_amount,
+                recipient,
+            } => {
+                writer.write_all(&[1])?;
+                writer.write_all(&target_relayer_fee.to_be_bytes())?;
+                writer.write_all(&to_native_token_amount.to_be_bytes())?;
+                writer.write_all(recipient)?;
+            }
+            TokenBridgeRelayerMessage::TransferWithRelayV2 {
+                target_relayer_fee,
+                to_native_token_amount,
+                recipient,
+                min_native_swap_output,
+            } => {
+                writer.write_all(&[2])?;
+                writer.write_all(&target_relayer_fee.to_be_bytes())?;
+                writer.write_all(&to_native_token_amount.to_be_bytes())?;
+                writer.write_all(recipient)?;
+                writer.write_all(&min_native_swap_output.to_be_bytes())?;
+            }
+            TokenBridgeRelayerMessage::TransferWithRelayV3 {
+                target_relayer_fee,
+                to_native_token_amount,
+                recipient,
+                min_native_swap_output,
+                reference_id,
+            } => {
+                writer.write_all(&[3])?;
+                writer.write_all(&target_relayer_fee.to_be_bytes())?;
+                writer.write_all(&to_native_token_amount.to_be_bytes())?;
+                writer.write_all(recipient)?;
+                writer.write_all(&min_native_swap_output.to_be_bytes())?;
+                writer.write_all(reference_id)?;
+            }
+            TokenBridgeRelayerMessage::TransferWithRelayV4 {
+                target_relayer_fee,
+                to_native_token_amount,
+                recipient,
+                min_native_swap_output,
+                reference_id,
+                memo,
+            } => {
+                writer.write_all(&[4])?;
+                writer.write_all(&target_relayer_fee.to_be_bytes())?;
+                writer.write_all(&to_native_token_amount.to_be_bytes())?;
+                writer.write_all(recipient)?;
+                writer.write_all(&min_native_swap_output.to_be_bytes())?;
+                writer.write_all(reference_id)?;
+                writer.write_all(memo)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+// `deserialize_reader` only ever returns via `?` on `read_exact` (short buffer) or the
+// `InvalidMessagePayloadId` branch below (unrecognized payload ID); see the round-trip and
+// malformed-input tests at the bottom of this file.
+impl AnchorDeserialize for TokenBridgeRelayerMessage {
+    fn deserialize_reader<R: io::Read>(reader: &mut R) -> io::Result<Self> {
+        let mut payload_id = [0u8; 1];
+        reader.read_exact(&mut payload_id)?;
+
+        match payload_id[0] {
+            1 => {
+                let mut target_relayer_fee = [0u8; 8];
+                reader.read_exact(&mut target_relayer_fee)?;
+
+                let mut to_native_token_amount = [0u8; 8];
+                reader.read_exact(&mut to_native_token_amount)?;
+
+                let mut recipient = [0u8; 32];
+                reader.read_exact(&mut recipient)?;
+                require_valid_recipient(recipient)?;
+
+                Ok(TokenBridgeRelayerMessage::TransferWithRelay {
+                    target_relayer_fee: u64::from_be_bytes(target_relayer_fee),
+                    to_native_token_amount: u64::from_be_bytes(to_native_token_amount),
+                    recipient,
+                })
+            }
+            2 => {
+                let mut target_relayer_fee = [0u8; 8];
+                reader.read_exact(&mut target_relayer_fee)?;
+
+                let mut to_native_token_amount = [0u8; 8];
+                reader.read_exact(&mut to_native_token_amount)?;
+
+                let mut recipient = [0u8; 32];
+                reader.read_exact(&mut recipient)?;
+                require_valid_recipient(recipient)?;
+
+                let mut min_native_swap_output = [0u8; 8];
+                reader.read_exact(&mut min_native_swap_output)?;
+
+                Ok(TokenBridgeRelayerMessage::TransferWithRelayV2 {
+                    target_relayer_fee: u64::from_be_bytes(target_relayer_fee),
+                    to_native_token_amount: u64::from_be_bytes(to_native_token_amount),
+                    recipient,
+                    min_native_swap_output: u64::from_be_bytes(min_native_swap_output),
+                })
+            }
+            3 => {
+                let mut target_relayer_fee = [0u8; 8];
+                reader.read_exact(&mut target_relayer_fee)?;
+
+                let mut to_native_token_amount = [0u8; 8];
+                reader.read_exact(&mut to_native_token_amount)?;
+
+                let mut recipient = [0u8; 32];
+                reader.read_exact(&mut recipient)?;
+                require_valid_recipient(recipient)?;
+
+                let mut min_native_swap_output = [0u8; 8];
+                reader.read_exact(&mut min_native_swap_output)?;
+
+                let mut reference_id = [0u8; 16];
+                reader.read_exact(&mut reference_id)?;
+
+                Ok(TokenBridgeRelayerMessage::TransferWithRelayV3 {
+                    target_relayer_fee: u64::from_be_bytes(target_relayer_fee),
+                    to_native_token_amount: u64::from_be_bytes(to_native_token_amount),
+                    recipient,
+                    min_native_swap_output: u64::from_be_bytes(min_native_swap_output),
+                    reference_id,
+                })
+            }
+            4 => {
+                let mut target_relayer_fee = [0u8; 8];
+                reader.read_exact(&mut target_relayer_fee)?;
+
+                let mut to_native_token_amount = [0u8; 8];
+                reader.read_exact(&mut to_native_token_amount)?;
+
+                let mut recipient = [0u8; 32];
+                reader.read_exact(&mut recipient)?;
+                require_valid_recipient(recipient)?;
+
+                let mut min_native_swap_output = [0u8; 8];
+                reader.read_exact(&mut min_native_swap_output)?;
+
+                let mut reference_id = [0u8; 16];
+                reader.read_exact(&mut reference_id)?;
+
+                let mut memo = [0u8; 32];
+                reader.read_exact(&mut memo)?;
+
+                Ok(TokenBridgeRelayerMessage::TransferWithRelayV4 {
+                    target_relayer_fee: u64::from_be_bytes(target_relayer_fee),
+                    to_native_token_amount: u64::from_be_bytes(to_native_token_amount),
+                    recipient,
+                    min_native_swap_output: u64::from_be_bytes(min_native_swap_output),
+                    reference_id,
+                    memo,
+                })
+            }
+            _ => Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!("{:?}", TokenBridgeRelayerError::InvalidMessagePayloadId),
+            )),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn recipient() -> [u8; 32] {
+        let mut recipient = [0u8; 32];
+        recipient[31] = 1;
+        recipient
+    }
+
+    #[test]
+    fn transfer_with_relay_round_trips() {
+        let message = TokenBridgeRelayerMessage::TransferWithRelay {
+            target_relayer_fee: 42,
+            to_native_token_amount: 7,
+            recipient: recipient(),
+        };
+        let encoded = message.try_to_vec().unwrap();
+        assert_eq!(encoded[0], 1);
+
+        let decoded = TokenBridgeRelayerMessage::try_from_slice(&encoded).unwrap();
+        assert_eq!(decoded.target_relayer_fee(), 42);
+        assert_eq!(decoded.to_native_token_amount(), 7);
+        assert_eq!(decoded.min_native_swap_output(), 0);
+        assert_eq!(decoded.reference_id(), [0u8; 16]);
+        assert_eq!(decoded.memo(), [0u8; 32]);
+    }
+
+    #[test]
+    fn transfer_with_relay_v2_round_trips() {
+        let message = TokenBridgeRelayerMessage::TransferWithRelayV2 {
+            target_relayer_fee: 42,
+            to_native_token_amount: 7,
+            recipient: recipient(),
+            min_native_swap_output: 123,
+        };
+        let encoded = message.try_to_vec().unwrap();
+        assert_eq!(encoded[0], 2);
+
+        let decoded = TokenBridgeRelayerMessage::try_from_slice(&encoded).unwrap();
+        assert_eq!(decoded.min_native_swap_output(), 123);
+        assert_eq!(decoded.reference_id(), [0u8; 16]);
+        assert_eq!(decoded.memo(), [0u8; 32]);
+    }
+
+    #[test]
+    fn transfer_with_relay_v3_round_trips() {
+        let mut reference_id = [0u8; 16];
+        reference_id[0] = 9;
+
+        let message = TokenBridgeRelayerMessage::TransferWithRelayV3 {
+            target_relayer_fee: 42,
+            to_native_token_amount: 7,
+            recipient: recipient(),
+            min_native_swap_output: 123,
+            reference_id,
+        };
+        let encoded = message.try_to_vec().unwrap();
+        assert_eq!(encoded[0], 3);
+
+        let decoded = TokenBridgeRelayerMessage::try_from_slice(&encoded).unwrap();
+        assert_eq!(decoded.reference_id(), reference_id);
+        assert_eq!(decoded.memo(), [0u8; 32]);
+    }
+
+    #[test]
+    fn transfer_with_relay_v4_round_trips() {
+        let mut reference_id = [0u8; 16];
+        reference_id[0] = 9;
+        let mut memo = [0u8; 32];
+        memo[0] = 5;
+
+        let message = TokenBridgeRelayerMessage::TransferWithRelayV4 {
+            target_relayer_fee: 42,
+            to_native_token_amount: 7,
+            recipient: recipient(),
+            min_native_swap_output: 123,
+            reference_id,
+            memo,
+        };
+        let encoded = message.try_to_vec().unwrap();
+        assert_eq!(encoded[0], 4);
+
+        let decoded = TokenBridgeRelayerMessage::try_from_slice(&encoded).unwrap();
+        assert_eq!(decoded.reference_id(), reference_id);
+        assert_eq!(decoded.memo(), memo);
+    }
+
+    #[test]
+    fn deserialize_rejects_zero_recipient() {
+        let message = TokenBridgeRelayerMessage::TransferWithRelay {
+            target_relayer_fee: 0,
+            to_native_token_amount: 0,
+            recipient: [0u8; 32],
+        };
+        let encoded = message.try_to_vec().unwrap();
+        assert!(TokenBridgeRelayerMessage::try_from_slice(&encoded).is_err());
+    }
+
+    #[test]
+    fn deserialize_rejects_reserved_program_address_recipient() {
+        let mut recipient = [0u8; 32];
+        recipient.copy_from_slice(crate::TOKEN_BRIDGE_PROGRAM_ID.as_ref());
+
+        let message = TokenBridgeRelayerMessage::TransferWithRelay {
+            target_relayer_fee: 0,
+            to_native_token_amount: 0,
+            recipient,
+        };
+        let encoded = message.try_to_vec().unwrap();
+        assert!(TokenBridgeRelayerMessage::try_from_slice(&encoded).is_err());
+    }
+
+    #[test]
+    fn deserialize_rejects_unknown_payload_id() {
+        let mut encoded = TokenBridgeRelayerMessage::TransferWithRelay {
+            target_relayer_fee: 0,
+            to_native_token_amount: 0,
+            recipient: recipient(),
+        }
+        .try_to_vec()
+        .unwrap();
+        encoded[0] = 255;
+
+        assert!(TokenBridgeRelayerMessage::try_from_slice(&encoded).is_err());
+    }
+
+    #[test]
+    fn deserialize_rejects_truncated_payload() {
+        let encoded = TokenBridgeRelayerMessage::TransferWithRelayV4 {
+            target_relayer_fee: 42,
+            to_native_token_amount: 7,
+            recipient: recipient(),
+            min_native_swap_output: 123,
+            reference_id: [9u8; 16],
+            memo: [5u8; 32],
+        }
+        .try_to_vec()
+        .unwrap();
+
+        assert!(TokenBridgeRelayerMessage::try_from_slice(&encoded[..encoded.len() - 1]).is_err());
+    }
+
+    #[test]
+    fn deserialize_rejects_trailing_bytes() {
+        let mut encoded = TokenBridgeRelayerMessage::TransferWithRelay {
+            target_relayer_fee: 42,
+            to_native_token_amount: 7,
+            recipient: recipient(),
+        }
+        .try_to_vec()
+        .unwrap();
+        encoded.push(0);
+
+        assert!(TokenBridgeRelayerMessage::try_from_slice(&encoded).is_err());
+    }
+}
@@ -0,0 +1,27 @@
+use crate::{events::RelayerEarningsReport, state::RelayerStats, SEED_PREFIX_RELAYER_STATS};
+use anchor_lang::prelude::*;
+
+/// View-only instruction that emits a relayer's earnings counters as an Anchor event, so
+/// off-chain clients can read them via transaction simulation without an indexer.
+#[derive(Accounts)]
+pub struct ClaimRelayerStatsReport<'info> {
+    pub relayer: Signer<'info>,
+
+    #[account(
+        seeds = [SEED_PREFIX_RELAYER_STATS, relayer.key().as_ref()],
+        bump,
+    )]
+    pub relayer_stats: Account<'info, RelayerStats>,
+}
+
+pub fn claim_relayer_stats_report(ctx: Context<ClaimRelayerStatsReport>) -> Result<()> {
+    emit!(RelayerEarningsReport {
+        relayer: ctx.accounts.relayer.key(),
+        total_transfers: ctx.accounts.relayer_stats.total_transfers,
+        total_tokens_earned: ctx.accounts.relayer_stats.total_tokens_earned,
+        total_native_earned: ctx.accounts.relayer_stats.total_native_earned,
+        last_transfer_slot: ctx.accounts.relayer_stats.last_transfer_slot,
+    });
+
+    Ok(())
+}
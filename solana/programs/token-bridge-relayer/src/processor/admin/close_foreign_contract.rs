@@ -0,0 +1,48 @@
+use crate::{
+    events::ForeignContractClosed,
+    state::{ForeignContract, OwnerConfig, SenderConfig},
+    SEED_PREFIX_FOREIGN_CONTRACT, SEED_PREFIX_SENDER,
+};
+use anchor_lang::prelude::*;
+
+/// Reclaims the rent-exempt lamports held by a `ForeignContract` PDA once the chain it
+/// represents is permanently deprecated. This does not touch any historical transfer data;
+/// VAAs already emitted for this chain remain on Wormhole guardian networks and can still be
+/// looked up off-chain. A closed registration can be re-created with `restore_foreign_contract`,
+/// which reuses the same PDA seeds.
+#[derive(Accounts)]
+#[instruction(chain: u16)]
+pub struct CloseForeignContract<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    #[account(
+        seeds = [SEED_PREFIX_SENDER],
+        bump = config.bump,
+        has_one = owner,
+    )]
+    pub config: Account<'info, SenderConfig>,
+
+    #[account(mut, seeds = [b"owner_config"], bump)]
+    pub owner_config: Account<'info, OwnerConfig>,
+
+    #[account(
+        mut,
+        close = owner,
+        seeds = [SEED_PREFIX_FOREIGN_CONTRACT, &chain.to_be_bytes()[..]],
+        bump,
+    )]
+    pub foreign_contract: Account<'info, ForeignContract>,
+}
+
+pub fn close_foreign_contract(ctx: Context<CloseForeignContract>, chain: u16) -> Result<()> {
+    ctx.accounts.owner_config.registered_contract_count = ctx
+        .accounts
+        .owner_config
+        .registered_contract_count
+        .saturating_sub(1);
+
+    emit!(ForeignContractClosed { chain });
+
+    Ok(())
+}
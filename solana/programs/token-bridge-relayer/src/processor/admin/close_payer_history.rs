@@ -0,0 +1,22 @@
+use crate::{state::PayerTransferHistory, SEED_PREFIX_PAYER_HISTORY};
+use anchor_lang::prelude::*;
+
+/// Reclaims the rent held by a payer's `PayerTransferHistory` PDA. Only the payer itself may
+/// close its own history account.
+#[derive(Accounts)]
+pub struct ClosePayerHistory<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    #[account(
+        mut,
+        close = payer,
+        seeds = [SEED_PREFIX_PAYER_HISTORY, payer.key().as_ref()],
+        bump,
+    )]
+    pub payer_transfer_history: Account<'info, PayerTransferHistory>,
+}
+
+pub fn close_payer_history(_ctx: Context<ClosePayerHistory>) -> Result<()> {
+    Ok(())
+}
@@ -0,0 +1,22 @@
+use crate::{state::RelayerStats, SEED_PREFIX_RELAYER_STATS};
+use anchor_lang::prelude::*;
+
+/// Reclaims the rent held by a relayer's `RelayerStats` PDA. Only the relayer itself may close
+/// its own stats account.
+#[derive(Accounts)]
+pub struct CloseRelayerStats<'info> {
+    #[account(mut)]
+    pub relayer: Signer<'info>,
+
+    #[account(
+        mut,
+        close = relayer,
+        seeds = [SEED_PREFIX_RELAYER_STATS, relayer.key().as_ref()],
+        bump,
+    )]
+    pub relayer_stats: Account<'info, RelayerStats>,
+}
+
+pub fn close_relayer_stats(_ctx: Context<CloseRelayerStats>) -> Result<()> {
+    Ok(())
+}
@@ -0,0 +1,45 @@
+use crate::{
+    error::TokenBridgeRelayerError, state::SenderConfig, state::SignerSequence, SEED_PREFIX_SENDER,
+    SEED_PREFIX_SIGNER_SEQUENCE,
+};
+use anchor_lang::prelude::*;
+
+/// Reclaims the rent held by a `SignerSequence` PDA for a `payer` who no longer transfers
+/// through the program. The sequence's `value` doesn't matter; the account can be closed
+/// regardless. If `payer` submits another transfer afterward, the PDA is re-initialized and its
+/// sequence starts over from `0`.
+#[derive(Accounts)]
+pub struct CloseSignerSequence<'info> {
+    pub signer: Signer<'info>,
+
+    #[account(
+        seeds = [SEED_PREFIX_SENDER],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, SenderConfig>,
+
+    /// CHECK: the payer whose `SignerSequence` PDA is being closed; only used to derive seeds.
+    pub payer: AccountInfo<'info>,
+
+    #[account(
+        mut,
+        close = destination,
+        seeds = [SEED_PREFIX_SIGNER_SEQUENCE, payer.key().as_ref()],
+        bump,
+    )]
+    pub signer_sequence: Account<'info, SignerSequence>,
+
+    /// CHECK: receives the reclaimed rent; caller-provided.
+    #[account(mut)]
+    pub destination: AccountInfo<'info>,
+}
+
+pub fn close_signer_sequence(ctx: Context<CloseSignerSequence>) -> Result<()> {
+    require!(
+        ctx.accounts.signer.key() == ctx.accounts.payer.key()
+            || ctx.accounts.signer.key() == ctx.accounts.config.owner,
+        TokenBridgeRelayerError::OwnerOrOriginalPayerOnly
+    );
+
+    Ok(())
+}
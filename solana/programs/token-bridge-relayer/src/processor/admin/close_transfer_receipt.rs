@@ -0,0 +1,37 @@
+use crate::{
+    state::{RedeemerConfig, TransferReceipt},
+    SEED_PREFIX_RECEIPT, SEED_PREFIX_REDEEMER,
+};
+use anchor_lang::prelude::*;
+
+/// Reclaims the rent held by a `TransferReceipt` PDA. Emergency garbage collection only —
+/// closing a receipt does not affect the underlying transfer, which has already settled;
+/// `redeemed_at_slot` and the amounts it recorded simply become unavailable off-chain.
+#[derive(Accounts)]
+#[instruction(vaa_hash: [u8; 32])]
+pub struct CloseTransferReceipt<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    #[account(
+        seeds = [SEED_PREFIX_REDEEMER],
+        bump = config.bump,
+        has_one = owner,
+    )]
+    pub config: Account<'info, RedeemerConfig>,
+
+    #[account(
+        mut,
+        close = owner,
+        seeds = [SEED_PREFIX_RECEIPT, &vaa_hash],
+        bump,
+    )]
+    pub transfer_receipt: Account<'info, TransferReceipt>,
+}
+
+pub fn close_transfer_receipt(
+    _ctx: Context<CloseTransferReceipt>,
+    _vaa_hash: [u8; 32],
+) -> Result<()> {
+    Ok(())
+}
@@ -0,0 +1,47 @@
+use crate::{
+    events::CircuitBreakerConfigured, state::CircuitBreaker, state::OwnerConfig,
+    SEED_PREFIX_CIRCUIT_BREAKER,
+};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct ConfigureCircuitBreaker<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    #[account(seeds = [b"owner_config"], bump, has_one = owner)]
+    pub owner_config: Account<'info, OwnerConfig>,
+
+    #[account(
+        init_if_needed,
+        payer = owner,
+        seeds = [SEED_PREFIX_CIRCUIT_BREAKER],
+        bump,
+        space = CircuitBreaker::MAXIMUM_SIZE,
+    )]
+    pub circuit_breaker: Account<'info, CircuitBreaker>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Sets whether the program-wide volume circuit breaker is enforced and its window/cap. Does not
+/// clear `tripped` — see `reset_circuit_breaker` for that. Owner-only, same as
+/// `reset_circuit_breaker`.
+pub fn configure_circuit_breaker(
+    ctx: Context<ConfigureCircuitBreaker>,
+    enabled: bool,
+    window_slots: u64,
+    max_volume_per_window: u64,
+) -> Result<()> {
+    ctx.accounts.circuit_breaker.enabled = enabled;
+    ctx.accounts.circuit_breaker.window_slots = window_slots;
+    ctx.accounts.circuit_breaker.max_volume_per_window = max_volume_per_window;
+
+    emit!(CircuitBreakerConfigured {
+        enabled,
+        window_slots,
+        max_volume_per_window,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,66 @@
+use crate::{
+    events::TokenRegistrationChanged,
+    state::{OwnerConfig, RegisteredToken, SenderConfig, TokenRegistry},
+    SEED_PREFIX_REGISTERED_TOKEN, SEED_PREFIX_SENDER, SEED_PREFIX_TOKEN_REGISTRY,
+};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct DeregisterToken<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    #[account(
+        seeds = [SEED_PREFIX_SENDER],
+        bump = config.bump,
+        has_one = owner,
+    )]
+    pub config: Account<'info, SenderConfig>,
+
+    #[account(mut, seeds = [b"owner_config"], bump)]
+    pub owner_config: Account<'info, OwnerConfig>,
+
+    /// CHECK: the mint being deregistered; only used to derive the registered-token PDA.
+    pub mint: AccountInfo<'info>,
+
+    #[account(
+        mut,
+        close = owner,
+        seeds = [SEED_PREFIX_REGISTERED_TOKEN, mint.key().as_ref()],
+        bump,
+    )]
+    pub registered_token: Account<'info, RegisteredToken>,
+
+    #[account(
+        mut,
+        seeds = [SEED_PREFIX_TOKEN_REGISTRY],
+        bump,
+        realloc = TokenRegistry::space_for(token_registry.mints.len().saturating_sub(1)),
+        realloc::payer = owner,
+        realloc::zero = false,
+    )]
+    pub token_registry: Account<'info, TokenRegistry>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn deregister_token(ctx: Context<DeregisterToken>) -> Result<()> {
+    ctx.accounts.owner_config.registered_token_count = ctx
+        .accounts
+        .owner_config
+        .registered_token_count
+        .saturating_sub(1);
+
+    let mint = ctx.accounts.mint.key();
+    let mints = &mut ctx.accounts.token_registry.mints;
+    if let Some(index) = mints.iter().position(|registered| *registered == mint) {
+        mints.swap_remove(index);
+    }
+
+    emit!(TokenRegistrationChanged {
+        mint,
+        is_registered: false,
+    });
+
+    Ok(())
+}
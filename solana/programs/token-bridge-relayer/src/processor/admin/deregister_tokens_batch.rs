@@ -0,0 +1,50 @@
+use crate::{
+    events::TokenDeregisteredBatch,
+    state::{RegisteredToken, SenderConfig},
+    SEED_PREFIX_SENDER,
+};
+use anchor_lang::prelude::*;
+
+/// Emergency-shutdown counterpart to `deregister_token`: closes as many `RegisteredToken`
+/// accounts as fit in `ctx.remaining_accounts` in a single transaction, so an operator can stop
+/// new transfers for every registered token during a security incident without waiting on one
+/// instruction per mint. Deliberately doesn't touch `OwnerConfig::registered_token_count` or
+/// `TokenRegistry::mints` the way `deregister_token` does: neither the mint nor the token
+/// registry's current length is available here, and re-deriving them isn't worth the added
+/// complexity for what's meant to be a blunt, minimal-context kill switch.
+#[derive(Accounts)]
+pub struct DeregisterTokensBatch<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    #[account(
+        seeds = [SEED_PREFIX_SENDER],
+        bump = config.bump,
+        has_one = owner,
+    )]
+    pub config: Account<'info, SenderConfig>,
+}
+
+pub fn deregister_tokens_batch<'info>(
+    ctx: Context<'_, '_, '_, 'info, DeregisterTokensBatch<'info>>,
+) -> Result<()> {
+    let mut count: u32 = 0;
+    for registered_token_info in ctx.remaining_accounts {
+        match Account::<'_, RegisteredToken>::try_from(registered_token_info) {
+            Ok(registered_token) => {
+                registered_token.close(ctx.accounts.owner.to_account_info())?;
+                count = count.saturating_add(1);
+            }
+            Err(_) => {
+                msg!(
+                    "deregister_tokens_batch: skipping {}, not a RegisteredToken account",
+                    registered_token_info.key()
+                );
+            }
+        }
+    }
+
+    emit!(TokenDeregisteredBatch { count });
+
+    Ok(())
+}
@@ -0,0 +1,153 @@
+use crate::{
+    error::TokenBridgeRelayerError,
+    events::GovernanceActionExecuted,
+    state::{ForeignContract, GovernanceClaim, OwnerConfig, RegisteredToken, SenderConfig},
+    GOVERNANCE_EMITTER_ADDRESS, GOVERNANCE_EMITTER_CHAIN, SEED_PREFIX_FOREIGN_CONTRACT,
+    SEED_PREFIX_GOVERNANCE_CLAIM, SEED_PREFIX_REGISTERED_TOKEN, SEED_PREFIX_SENDER,
+};
+use anchor_lang::prelude::*;
+
+pub const GOVERNANCE_ACTION_UPDATE_FEE: u8 = 1;
+pub const GOVERNANCE_ACTION_PAUSE_TRANSFERS: u8 = 2;
+pub const GOVERNANCE_ACTION_UPDATE_SWAP_RATE: u8 = 3;
+
+/// Decoded payload of a Wormhole governance VAA accepted by `execute_governance_action`.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub enum GovernanceAction {
+    UpdateFee { chain: u16, fee: u64 },
+    PauseTransfers { paused: bool },
+    UpdateSwapRate { mint: [u8; 32], swap_rate: u64 },
+}
+
+impl GovernanceAction {
+    pub fn action_type(&self) -> u8 {
+        match self {
+            GovernanceAction::UpdateFee { .. } => GOVERNANCE_ACTION_UPDATE_FEE,
+            GovernanceAction::PauseTransfers { .. } => GOVERNANCE_ACTION_PAUSE_TRANSFERS,
+            GovernanceAction::UpdateSwapRate { .. } => GOVERNANCE_ACTION_UPDATE_SWAP_RATE,
+        }
+    }
+}
+
+#[derive(Accounts)]
+#[instruction(vaa_hash: [u8; 32])]
+pub struct ExecuteGovernanceAction<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    #[account(seeds = [b"owner_config"], bump)]
+    pub owner_config: Account<'info, OwnerConfig>,
+
+    /// CHECK: the account `action` mutates — a `ForeignContract` PDA for `UpdateFee`, the
+    /// singleton `SenderConfig` for `PauseTransfers`, or a `RegisteredToken` PDA for
+    /// `UpdateSwapRate`. Which PDA that is depends on the decoded `action`, so its address is
+    /// derived and checked in the handler rather than via a `seeds` constraint here.
+    #[account(mut)]
+    pub target: AccountInfo<'info>,
+
+    #[account(
+        init,
+        payer = payer,
+        seeds = [SEED_PREFIX_GOVERNANCE_CLAIM, &vaa_hash],
+        bump,
+        space = GovernanceClaim::MAXIMUM_SIZE,
+    )]
+    pub governance_claim: Account<'info, GovernanceClaim>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Applies a governance action originating from the Wormhole governance emitter.
+///
+/// This program doesn't integrate the Wormhole core bridge (see the stub notes in
+/// [`crate::processor::complete_native_transfer_with_relay`]), so there is no guardian set or
+/// posted-VAA account to verify a signature against here, which means `emitter_chain`/
+/// `emitter_address` matching [`GOVERNANCE_EMITTER_CHAIN`]/[`GOVERNANCE_EMITTER_ADDRESS`] proves
+/// nothing on its own — both are public constants baked into the program binary, not secrets a
+/// caller needs a real VAA to produce. Until a genuine posted-VAA/guardian-signature check is
+/// wired in, this instruction is additionally gated the same way every other admin instruction
+/// is: `payer` must be the owner or assistant per `owner_config`.
+pub fn execute_governance_action(
+    ctx: Context<ExecuteGovernanceAction>,
+    vaa_hash: [u8; 32],
+    emitter_chain: u16,
+    emitter_address: [u8; 32],
+    action: GovernanceAction,
+) -> Result<()> {
+    require!(
+        ctx.accounts
+            .owner_config
+            .is_authorized(&ctx.accounts.payer.key()),
+        TokenBridgeRelayerError::OwnerOrAssistantOnly
+    );
+    require_eq!(
+        emitter_chain,
+        GOVERNANCE_EMITTER_CHAIN,
+        TokenBridgeRelayerError::InvalidWormholeEmitter
+    );
+    require!(
+        emitter_address == GOVERNANCE_EMITTER_ADDRESS,
+        TokenBridgeRelayerError::InvalidWormholeEmitter
+    );
+
+    let action_type = action.action_type();
+
+    match action {
+        GovernanceAction::UpdateFee { chain, fee } => {
+            let (expected, _bump) = Pubkey::find_program_address(
+                &[SEED_PREFIX_FOREIGN_CONTRACT, &chain.to_be_bytes()],
+                ctx.program_id,
+            );
+            require_keys_eq!(
+                ctx.accounts.target.key(),
+                expected,
+                TokenBridgeRelayerError::InvalidGovernanceTarget
+            );
+
+            let mut foreign_contract = Account::<ForeignContract>::try_from(&ctx.accounts.target)?;
+            foreign_contract.fee = fee;
+            foreign_contract.exit(ctx.program_id)?;
+        }
+        GovernanceAction::PauseTransfers { paused } => {
+            let (expected, _bump) =
+                Pubkey::find_program_address(&[SEED_PREFIX_SENDER], ctx.program_id);
+            require_keys_eq!(
+                ctx.accounts.target.key(),
+                expected,
+                TokenBridgeRelayerError::InvalidGovernanceTarget
+            );
+
+            let mut config = Account::<SenderConfig>::try_from(&ctx.accounts.target)?;
+            config.paused = paused;
+            config.exit(ctx.program_id)?;
+        }
+        GovernanceAction::UpdateSwapRate { mint, swap_rate } => {
+            let (expected, _bump) = Pubkey::find_program_address(
+                &[SEED_PREFIX_REGISTERED_TOKEN, &mint],
+                ctx.program_id,
+            );
+            require_keys_eq!(
+                ctx.accounts.target.key(),
+                expected,
+                TokenBridgeRelayerError::InvalidGovernanceTarget
+            );
+
+            let mut registered_token = Account::<RegisteredToken>::try_from(&ctx.accounts.target)?;
+            registered_token.swap_rate = swap_rate;
+            registered_token.last_swap_rate_update = Clock::get()?.slot;
+            registered_token.exit(ctx.program_id)?;
+        }
+    }
+
+    ctx.accounts.governance_claim.set_inner(GovernanceClaim {
+        executed_at_slot: Clock::get()?.slot,
+        action_type,
+    });
+
+    emit!(GovernanceActionExecuted {
+        vaa_hash,
+        action_type,
+    });
+
+    Ok(())
+}
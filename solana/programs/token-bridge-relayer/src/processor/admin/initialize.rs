@@ -0,0 +1,173 @@
+use crate::{
+    error::TokenBridgeRelayerError,
+    state::{
+        OwnerConfig, ProgramVersion, ProgramVersionConfig, RedeemerConfig, SenderConfig,
+        TokenRegistry,
+    },
+    PROGRAM_VERSION_MAJOR, PROGRAM_VERSION_MINOR, PROGRAM_VERSION_PATCH,
+    SEED_PREFIX_PROGRAM_VERSION, SEED_PREFIX_PROGRAM_VERSION_CONFIG, SEED_PREFIX_REDEEMER,
+    SEED_PREFIX_SENDER, SEED_PREFIX_TOKEN_REGISTRY, WORMHOLE_CORE_BRIDGE_PROGRAM_ID,
+};
+use anchor_lang::prelude::*;
+
+/// Wormhole core bridge's own seed prefix for its per-emitter `SequenceTracker` PDA. Hardcoded
+/// (rather than pulled from a `wormhole` crate, which isn't a dependency of this program) since
+/// it's a stable part of the core bridge's on-chain layout.
+const WORMHOLE_SEQUENCE_TRACKER_SEED_PREFIX: &[u8] = b"Sequence";
+
+#[derive(Accounts)]
+pub struct Initialize<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    #[account(
+        init,
+        payer = owner,
+        seeds = [SEED_PREFIX_SENDER],
+        bump,
+        space = SenderConfig::MAXIMUM_SIZE,
+    )]
+    pub sender_config: Account<'info, SenderConfig>,
+
+    #[account(
+        init,
+        payer = owner,
+        seeds = [SEED_PREFIX_REDEEMER],
+        bump,
+        space = RedeemerConfig::MAXIMUM_SIZE,
+    )]
+    pub redeemer_config: Account<'info, RedeemerConfig>,
+
+    #[account(
+        init,
+        payer = owner,
+        seeds = [b"owner_config"],
+        bump,
+        space = OwnerConfig::MAXIMUM_SIZE,
+    )]
+    pub owner_config: Account<'info, OwnerConfig>,
+
+    #[account(
+        init,
+        payer = owner,
+        seeds = [SEED_PREFIX_TOKEN_REGISTRY],
+        bump,
+        space = TokenRegistry::space_for(0),
+    )]
+    pub token_registry: Account<'info, TokenRegistry>,
+
+    #[account(
+        init,
+        payer = owner,
+        seeds = [SEED_PREFIX_PROGRAM_VERSION_CONFIG],
+        bump,
+        space = ProgramVersionConfig::MAXIMUM_SIZE,
+    )]
+    pub program_version_config: Account<'info, ProgramVersionConfig>,
+
+    #[account(
+        init,
+        payer = owner,
+        seeds = [SEED_PREFIX_PROGRAM_VERSION, &0u64.to_be_bytes()],
+        bump,
+        space = ProgramVersion::MAXIMUM_SIZE,
+    )]
+    pub program_version: Account<'info, ProgramVersion>,
+
+    /// CHECK: the Token Bridge program's registered emitter for this program; only used to
+    /// derive `token_bridge_sequence`'s expected address below.
+    pub token_bridge_emitter: AccountInfo<'info>,
+
+    /// CHECK: must be the Wormhole core bridge's `SequenceTracker` PDA for
+    /// `token_bridge_emitter`. A deployer passing the wrong sequence account here would silently
+    /// point every outbound transfer at the wrong Wormhole message sequence, so this is checked
+    /// against the one address the core bridge would actually use rather than trusted as given.
+    #[account(
+        address = Pubkey::find_program_address(
+            &[WORMHOLE_SEQUENCE_TRACKER_SEED_PREFIX, token_bridge_emitter.key().as_ref()],
+            &WORMHOLE_CORE_BRIDGE_PROGRAM_ID,
+        ).0 @ TokenBridgeRelayerError::InvalidTokenBridgeSequence,
+    )]
+    pub token_bridge_sequence: AccountInfo<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn initialize(ctx: Context<Initialize>, relayer_fee_precision: u32) -> Result<()> {
+    require!(
+        relayer_fee_precision > 0,
+        TokenBridgeRelayerError::RelayerFeePrecisionCannotBeZero
+    );
+
+    let owner = ctx.accounts.owner.key();
+
+    let sender_config = &mut ctx.accounts.sender_config;
+    sender_config.owner = owner;
+    sender_config.bump = ctx.bumps["sender_config"];
+    sender_config.paused = false;
+    sender_config.relayer_fee_precision = relayer_fee_precision;
+    sender_config.token_bridge.emitter = ctx.accounts.token_bridge_emitter.key();
+    sender_config.token_bridge.sequence = ctx.accounts.token_bridge_sequence.key();
+
+    let redeemer_config = &mut ctx.accounts.redeemer_config;
+    redeemer_config.owner = owner;
+    redeemer_config.bump = ctx.bumps["redeemer_config"];
+    redeemer_config.relayer_fee_precision = relayer_fee_precision;
+    redeemer_config.inbound_paused = false;
+    redeemer_config.fee_recipient = owner;
+    redeemer_config.whitelist_enabled = false;
+    redeemer_config.secondary_fee_recipient = None;
+    redeemer_config.fee_split_bps = 10_000;
+
+    let owner_config = &mut ctx.accounts.owner_config;
+    owner_config.owner = owner;
+    owner_config.assistant = owner;
+    owner_config.pending_owner = None;
+    owner_config.max_foreign_contracts = OwnerConfig::DEFAULT_MAX_FOREIGN_CONTRACTS;
+    owner_config.registered_contract_count = 0;
+    owner_config.max_registered_tokens = OwnerConfig::DEFAULT_MAX_REGISTERED_TOKENS;
+    owner_config.registered_token_count = 0;
+
+    ctx.accounts.program_version.set_inner(ProgramVersion {
+        major: PROGRAM_VERSION_MAJOR,
+        minor: PROGRAM_VERSION_MINOR,
+        patch: PROGRAM_VERSION_PATCH,
+        deploy_slot: Clock::get()?.slot,
+        deployer: owner,
+    });
+    ctx.accounts.program_version_config.counter = 1;
+
+    // Anchor allocates exactly `space` bytes for an `init` account, so these should never trip.
+    // They exist to catch a future edit that changes a struct's fields without updating its
+    // `MAXIMUM_SIZE`, which would otherwise silently corrupt every account created afterward.
+    require_eq!(
+        ctx.accounts.sender_config.to_account_info().data_len(),
+        SenderConfig::MAXIMUM_SIZE,
+        TokenBridgeRelayerError::AccountSizeMismatch
+    );
+    require_eq!(
+        ctx.accounts.redeemer_config.to_account_info().data_len(),
+        RedeemerConfig::MAXIMUM_SIZE,
+        TokenBridgeRelayerError::AccountSizeMismatch
+    );
+    require_eq!(
+        ctx.accounts.owner_config.to_account_info().data_len(),
+        OwnerConfig::MAXIMUM_SIZE,
+        TokenBridgeRelayerError::AccountSizeMismatch
+    );
+    require_eq!(
+        ctx.accounts
+            .program_version_config
+            .to_account_info()
+            .data_len(),
+        ProgramVersionConfig::MAXIMUM_SIZE,
+        TokenBridgeRelayerError::AccountSizeMismatch
+    );
+    require_eq!(
+        ctx.accounts.program_version.to_account_info().data_len(),
+        ProgramVersion::MAXIMUM_SIZE,
+        TokenBridgeRelayerError::AccountSizeMismatch
+    );
+
+    Ok(())
+}
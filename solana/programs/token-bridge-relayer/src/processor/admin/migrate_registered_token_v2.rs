@@ -0,0 +1,83 @@
+use crate::{
+    error::TokenBridgeRelayerError,
+    events::RegisteredTokenMigrated,
+    state::{RegisteredToken, RegisteredTokenV1, SenderConfig},
+    SEED_PREFIX_REGISTERED_TOKEN, SEED_PREFIX_SENDER,
+};
+use anchor_lang::prelude::*;
+
+/// `registered_token` is taken as a raw `AccountInfo` rather than `Account<RegisteredToken>`
+/// because a `migrate_registered_token`-ed (version 1) account still carries the removed
+/// `is_registered` field at a byte offset the current struct no longer has, and would fail typed
+/// deserialization before this instruction gets a chance to rewrite it.
+#[derive(Accounts)]
+pub struct MigrateRegisteredTokenV2<'info> {
+    pub owner: Signer<'info>,
+
+    #[account(
+        seeds = [SEED_PREFIX_SENDER],
+        bump = config.bump,
+        has_one = owner,
+    )]
+    pub config: Account<'info, SenderConfig>,
+
+    /// CHECK: manually deserialized as `RegisteredTokenV1`, rewritten in the current
+    /// `RegisteredToken` layout, and reallocated down below.
+    #[account(
+        mut,
+        seeds = [SEED_PREFIX_REGISTERED_TOKEN, mint.key().as_ref()],
+        bump,
+    )]
+    pub registered_token: AccountInfo<'info>,
+
+    /// CHECK: only used to derive `registered_token`'s seeds; never read or written.
+    pub mint: AccountInfo<'info>,
+}
+
+pub fn migrate_registered_token_v2(ctx: Context<MigrateRegisteredTokenV2>) -> Result<()> {
+    let registered_token_info = &ctx.accounts.registered_token;
+    let old_size = registered_token_info.data_len() as u64;
+
+    require!(
+        old_size as usize == RegisteredTokenV1::SIZE,
+        TokenBridgeRelayerError::AlreadyMigrated
+    );
+
+    let migrated = {
+        let data = registered_token_info.try_borrow_data()?;
+        let mut cursor: &[u8] = &data[8..];
+        let old = RegisteredTokenV1::deserialize(&mut cursor)?;
+        require_eq!(old.version, 1, TokenBridgeRelayerError::AlreadyMigrated);
+
+        RegisteredToken {
+            swap_rate: old.swap_rate,
+            max_native_swap_amount: old.max_native_swap_amount,
+            last_swap_rate_update: old.last_swap_rate_update,
+            cumulative_volume_in: old.cumulative_volume_in,
+            cumulative_volume_out: old.cumulative_volume_out,
+            decimals: old.decimals,
+            swap_enabled: old.swap_enabled,
+            max_transfer_amount: old.max_transfer_amount,
+            max_fee_bps: old.max_fee_bps,
+            version: RegisteredToken::CURRENT_VERSION,
+            swap_rate_precision_override: 0,
+            max_native_swap_per_tx: 0,
+        }
+    };
+
+    registered_token_info.realloc(RegisteredToken::MAXIMUM_SIZE, false)?;
+
+    {
+        let mut data = registered_token_info.try_borrow_mut_data()?;
+        let mut cursor: &mut [u8] = &mut data[8..];
+        migrated.serialize(&mut cursor)?;
+    }
+
+    emit!(RegisteredTokenMigrated {
+        mint: ctx.accounts.mint.key(),
+        old_size,
+        new_size: RegisteredToken::MAXIMUM_SIZE as u64,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,93 @@
+use crate::{
+    error::TokenBridgeRelayerError,
+    events::SenderConfigMigrated,
+    state::{OutboundTokenBridgeAddresses, SenderConfig},
+    SEED_PREFIX_SENDER,
+};
+use anchor_lang::prelude::*;
+
+/// Byte-for-byte layout of `SenderConfig` before `swap_rate_precision` was removed in favor of
+/// the `SWAP_RATE_PRECISION` constant, used only to read a pre-migration account's data with the
+/// field offsets it was actually written with.
+#[derive(AnchorSerialize, AnchorDeserialize)]
+struct SenderConfigV0 {
+    owner: Pubkey,
+    bump: u8,
+    token_bridge: OutboundTokenBridgeAddresses,
+    paused: bool,
+    relayer_fee_precision: u32,
+    swap_rate_precision: u32,
+    max_swap_rate_age_slots: u64,
+    rate_limit_window_slots: u64,
+    rate_limit_max_amount: u64,
+}
+
+const SENDER_CONFIG_V0_SIZE: usize = SenderConfig::MAXIMUM_SIZE + 4;
+
+/// `config` is taken as a raw `AccountInfo` rather than `Account<SenderConfig>` because a
+/// pre-migration account is a different size (and, past the `relayer_fee_precision` field, a
+/// different byte layout) than `SenderConfig::MAXIMUM_SIZE` and would fail typed deserialization
+/// before this instruction gets a chance to rewrite it.
+#[derive(Accounts)]
+pub struct MigrateSenderConfig<'info> {
+    pub owner: Signer<'info>,
+
+    /// CHECK: manually deserialized as `SenderConfigV0`, rewritten in the current `SenderConfig`
+    /// layout, and reallocated down below.
+    #[account(
+        mut,
+        seeds = [SEED_PREFIX_SENDER],
+        bump,
+    )]
+    pub config: AccountInfo<'info>,
+}
+
+pub fn migrate_sender_config(ctx: Context<MigrateSenderConfig>) -> Result<()> {
+    let config_info = &ctx.accounts.config;
+    let old_size = config_info.data_len() as u64;
+
+    require!(
+        old_size as usize == SENDER_CONFIG_V0_SIZE,
+        TokenBridgeRelayerError::AlreadyMigrated
+    );
+
+    let new_config = {
+        let data = config_info.try_borrow_data()?;
+        let mut cursor: &[u8] = &data[8..];
+        let old = SenderConfigV0::deserialize(&mut cursor)?;
+
+        require_keys_eq!(
+            old.owner,
+            ctx.accounts.owner.key(),
+            TokenBridgeRelayerError::OwnerOnly
+        );
+
+        SenderConfig {
+            owner: old.owner,
+            bump: old.bump,
+            token_bridge: old.token_bridge,
+            paused: old.paused,
+            relayer_fee_precision: old.relayer_fee_precision,
+            max_swap_rate_age_slots: old.max_swap_rate_age_slots,
+            rate_limit_window_slots: old.rate_limit_window_slots,
+            rate_limit_max_amount: old.rate_limit_max_amount,
+            large_transfer_threshold: 0,
+            wormhole_message_fee: 0,
+        }
+    };
+
+    config_info.realloc(SenderConfig::MAXIMUM_SIZE, false)?;
+
+    {
+        let mut data = config_info.try_borrow_mut_data()?;
+        let mut cursor: &mut [u8] = &mut data[8..];
+        new_config.serialize(&mut cursor)?;
+    }
+
+    emit!(SenderConfigMigrated {
+        old_size,
+        new_size: SenderConfig::MAXIMUM_SIZE as u64,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,188 @@
+mod initialize;
+pub use initialize::*;
+
+mod register_foreign_contract;
+pub use register_foreign_contract::*;
+
+mod register_token;
+pub use register_token::*;
+
+mod deregister_token;
+pub use deregister_token::*;
+
+mod deregister_tokens_batch;
+pub use deregister_tokens_batch::*;
+
+mod update_relayer_fee;
+pub use update_relayer_fee::*;
+
+mod update_fee_native_token;
+pub use update_fee_native_token::*;
+
+mod update_relayer_fees_batch;
+pub use update_relayer_fees_batch::*;
+
+mod update_swap_rate;
+pub use update_swap_rate::*;
+
+mod set_token_swap_rate_precision;
+pub use set_token_swap_rate_precision::*;
+
+mod update_max_native_swap_amount;
+pub use update_max_native_swap_amount::*;
+
+mod update_max_native_swap_per_tx;
+pub use update_max_native_swap_per_tx::*;
+
+mod set_epoch_fee_schedule;
+pub use set_epoch_fee_schedule::*;
+
+mod update_assistant;
+pub use update_assistant::*;
+
+mod update_fee_recipient;
+pub use update_fee_recipient::*;
+
+mod ownership_transfer;
+pub use ownership_transfer::*;
+
+mod set_pause_for_transfers;
+pub use set_pause_for_transfers::*;
+
+mod set_pause_for_inbound_transfers;
+pub use set_pause_for_inbound_transfers::*;
+
+mod reclaim_tmp_account;
+pub use reclaim_tmp_account::*;
+
+mod update_max_swap_rate_age;
+pub use update_max_swap_rate_age::*;
+
+mod update_precision;
+pub use update_precision::*;
+
+mod reset_cumulative_volume;
+pub use reset_cumulative_volume::*;
+
+mod update_fee_floor;
+pub use update_fee_floor::*;
+
+mod update_fee_ceiling;
+pub use update_fee_ceiling::*;
+
+mod reset_program_stats;
+pub use reset_program_stats::*;
+
+mod reset_chain_stats;
+pub use reset_chain_stats::*;
+
+mod set_foreign_contract_active;
+pub use set_foreign_contract_active::*;
+
+mod set_fee_denomination_mode;
+pub use set_fee_denomination_mode::*;
+
+mod set_chain_volume_limit;
+pub use set_chain_volume_limit::*;
+
+mod configure_circuit_breaker;
+pub use configure_circuit_breaker::*;
+
+mod reset_circuit_breaker;
+pub use reset_circuit_breaker::*;
+
+mod relayer_whitelist;
+pub use relayer_whitelist::*;
+
+mod update_rate_limit_params;
+pub use update_rate_limit_params::*;
+
+mod update_large_transfer_threshold;
+pub use update_large_transfer_threshold::*;
+
+mod update_wormhole_message_fee;
+pub use update_wormhole_message_fee::*;
+
+mod set_token_swap_enabled;
+pub use set_token_swap_enabled::*;
+
+mod close_foreign_contract;
+pub use close_foreign_contract::*;
+
+mod restore_foreign_contract;
+pub use restore_foreign_contract::*;
+
+mod update_fee_split;
+pub use update_fee_split::*;
+
+mod close_signer_sequence;
+pub use close_signer_sequence::*;
+
+mod close_relayer_stats;
+pub use close_relayer_stats::*;
+
+mod claim_relayer_stats_report;
+pub use claim_relayer_stats_report::*;
+
+mod update_max_transfer_amount;
+pub use update_max_transfer_amount::*;
+
+mod read_audit_log;
+pub use read_audit_log::*;
+
+mod recipient_blacklist;
+pub use recipient_blacklist::*;
+
+mod set_timelock_delay;
+pub use set_timelock_delay::*;
+
+mod close_transfer_receipt;
+pub use close_transfer_receipt::*;
+
+mod source_chain_allowlist;
+pub use source_chain_allowlist::*;
+
+mod update_max_fee_bps;
+pub use update_max_fee_bps::*;
+
+mod record_upgrade;
+pub use record_upgrade::*;
+
+mod update_foreign_endpoint;
+pub use update_foreign_endpoint::*;
+
+mod migrate_registered_token;
+pub use migrate_registered_token::*;
+
+mod migrate_registered_token_v2;
+pub use migrate_registered_token_v2::*;
+
+mod migrate_registered_token_v3;
+pub use migrate_registered_token_v3::*;
+
+mod migrate_sender_config;
+pub use migrate_sender_config::*;
+
+mod update_max_foreign_contracts;
+pub use update_max_foreign_contracts::*;
+
+mod supported_chain_allowlist;
+pub use supported_chain_allowlist::*;
+
+mod oracle;
+pub use oracle::*;
+
+mod multisig;
+pub use multisig::*;
+
+mod governance;
+pub use governance::*;
+
+mod close_payer_history;
+pub use close_payer_history::*;
+
+mod update_max_registered_tokens;
+pub use update_max_registered_tokens::*;
+
+mod revoke_upgrade_authority;
+pub use revoke_upgrade_authority::*;
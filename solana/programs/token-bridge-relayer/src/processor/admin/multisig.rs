@@ -0,0 +1,286 @@
+use crate::{
+    error::TokenBridgeRelayerError,
+    events::{
+        MultisigActionApproved, MultisigActionExecuted, MultisigActionProposed, MultisigInitialized,
+    },
+    state::{
+        MultisigConfig, PendingMultisigAction, SenderConfig, MULTISIG_ACTION_ADD_SIGNER,
+        MULTISIG_ACTION_REMOVE_SIGNER, MULTISIG_ACTION_SET_THRESHOLD,
+    },
+    SEED_PREFIX_MULTISIG, SEED_PREFIX_PENDING_MULTISIG_ACTION, SEED_PREFIX_SENDER,
+};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct InitMultisig<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    #[account(
+        seeds = [SEED_PREFIX_SENDER],
+        bump = config.bump,
+        has_one = owner,
+    )]
+    pub config: Account<'info, SenderConfig>,
+
+    #[account(
+        init,
+        payer = owner,
+        seeds = [SEED_PREFIX_MULTISIG],
+        bump,
+        space = MultisigConfig::MAXIMUM_SIZE,
+    )]
+    pub multisig_config: Account<'info, MultisigConfig>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Creates the singleton `MultisigConfig`, called once by the current owner to bootstrap
+/// multisig governance of its own membership. See the module doc on [`MultisigConfig`] for what
+/// is (and isn't yet) governed this way.
+pub fn init_multisig(
+    ctx: Context<InitMultisig>,
+    signers: Vec<Pubkey>,
+    threshold: u8,
+) -> Result<()> {
+    require!(
+        !signers.is_empty() && signers.len() <= MultisigConfig::MAX_SIGNERS,
+        TokenBridgeRelayerError::TooManyMultisigSigners
+    );
+    require!(
+        threshold > 0 && threshold as usize <= signers.len(),
+        TokenBridgeRelayerError::InvalidMultisigThreshold
+    );
+    for (index, signer) in signers.iter().enumerate() {
+        require!(
+            !signers[..index].contains(signer),
+            TokenBridgeRelayerError::DuplicateMultisigSigner
+        );
+    }
+
+    ctx.accounts.multisig_config.set_inner(MultisigConfig {
+        signers: signers.clone(),
+        threshold,
+        enabled: true,
+        pending_action_nonce: 0,
+    });
+
+    emit!(MultisigInitialized { signers, threshold });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(action_type: u8, encoded_args: Vec<u8>)]
+pub struct ProposeMultisigAction<'info> {
+    #[account(mut)]
+    pub proposer: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [SEED_PREFIX_MULTISIG],
+        bump,
+    )]
+    pub multisig_config: Account<'info, MultisigConfig>,
+
+    #[account(
+        init,
+        payer = proposer,
+        seeds = [
+            SEED_PREFIX_PENDING_MULTISIG_ACTION,
+            &multisig_config.pending_action_nonce.to_be_bytes(),
+        ],
+        bump,
+        space = PendingMultisigAction::space_for(encoded_args.len()),
+    )]
+    pub pending_action: Account<'info, PendingMultisigAction>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Proposes a `MultisigConfig` mutation (one of `MULTISIG_ACTION_ADD_SIGNER`,
+/// `MULTISIG_ACTION_REMOVE_SIGNER`, `MULTISIG_ACTION_SET_THRESHOLD`) and records the proposer's
+/// own approval, so a lone `approve_multisig_action` call per remaining signer is enough to
+/// reach threshold.
+pub fn propose_multisig_action(
+    ctx: Context<ProposeMultisigAction>,
+    action_type: u8,
+    encoded_args: Vec<u8>,
+) -> Result<()> {
+    require!(
+        ctx.accounts.multisig_config.enabled,
+        TokenBridgeRelayerError::NotMultisigSigner
+    );
+    require!(
+        ctx.accounts
+            .multisig_config
+            .signers
+            .contains(&ctx.accounts.proposer.key()),
+        TokenBridgeRelayerError::NotMultisigSigner
+    );
+
+    let nonce = ctx.accounts.multisig_config.pending_action_nonce;
+
+    ctx.accounts
+        .pending_action
+        .set_inner(PendingMultisigAction {
+            nonce,
+            action_type,
+            encoded_args,
+            approvals: vec![ctx.accounts.proposer.key()],
+            executed: false,
+        });
+
+    ctx.accounts.multisig_config.pending_action_nonce = nonce.saturating_add(1);
+
+    emit!(MultisigActionProposed {
+        nonce,
+        action_type,
+        proposer: ctx.accounts.proposer.key(),
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(nonce: u64)]
+pub struct ApproveMultisigAction<'info> {
+    pub approver: Signer<'info>,
+
+    #[account(
+        seeds = [SEED_PREFIX_MULTISIG],
+        bump,
+    )]
+    pub multisig_config: Account<'info, MultisigConfig>,
+
+    #[account(
+        mut,
+        seeds = [SEED_PREFIX_PENDING_MULTISIG_ACTION, &nonce.to_be_bytes()],
+        bump,
+    )]
+    pub pending_action: Account<'info, PendingMultisigAction>,
+}
+
+pub fn approve_multisig_action(ctx: Context<ApproveMultisigAction>, _nonce: u64) -> Result<()> {
+    require!(
+        !ctx.accounts.pending_action.executed,
+        TokenBridgeRelayerError::MultisigActionAlreadyExecuted
+    );
+    require!(
+        ctx.accounts
+            .multisig_config
+            .signers
+            .contains(&ctx.accounts.approver.key()),
+        TokenBridgeRelayerError::NotMultisigSigner
+    );
+    require!(
+        !ctx.accounts
+            .pending_action
+            .approvals
+            .contains(&ctx.accounts.approver.key()),
+        TokenBridgeRelayerError::MultisigActionAlreadyApproved
+    );
+
+    ctx.accounts
+        .pending_action
+        .approvals
+        .push(ctx.accounts.approver.key());
+
+    emit!(MultisigActionApproved {
+        nonce: ctx.accounts.pending_action.nonce,
+        approver: ctx.accounts.approver.key(),
+        approvals: ctx.accounts.pending_action.approvals.len() as u8,
+        threshold: ctx.accounts.multisig_config.threshold,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(nonce: u64)]
+pub struct ExecuteMultisigAction<'info> {
+    pub executor: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [SEED_PREFIX_MULTISIG],
+        bump,
+    )]
+    pub multisig_config: Account<'info, MultisigConfig>,
+
+    #[account(
+        mut,
+        seeds = [SEED_PREFIX_PENDING_MULTISIG_ACTION, &nonce.to_be_bytes()],
+        bump,
+    )]
+    pub pending_action: Account<'info, PendingMultisigAction>,
+}
+
+/// Applies a `PendingMultisigAction` once `approvals.len() >= MultisigConfig::threshold`.
+/// Callable by anyone, matching `execute_*` timelock instructions elsewhere in this program: the
+/// approvals recorded on-chain are what authorizes the mutation, not the caller of this
+/// instruction.
+pub fn execute_multisig_action(ctx: Context<ExecuteMultisigAction>, _nonce: u64) -> Result<()> {
+    require!(
+        !ctx.accounts.pending_action.executed,
+        TokenBridgeRelayerError::MultisigActionAlreadyExecuted
+    );
+    require!(
+        ctx.accounts.pending_action.approvals.len() as u8 >= ctx.accounts.multisig_config.threshold,
+        TokenBridgeRelayerError::MultisigThresholdNotMet
+    );
+
+    let action_type = ctx.accounts.pending_action.action_type;
+    let encoded_args = ctx.accounts.pending_action.encoded_args.clone();
+    let mut cursor: &[u8] = &encoded_args;
+
+    match action_type {
+        MULTISIG_ACTION_ADD_SIGNER => {
+            let new_signer = Pubkey::deserialize(&mut cursor)?;
+            require!(
+                ctx.accounts.multisig_config.signers.len() < MultisigConfig::MAX_SIGNERS,
+                TokenBridgeRelayerError::TooManyMultisigSigners
+            );
+            require!(
+                !ctx.accounts.multisig_config.signers.contains(&new_signer),
+                TokenBridgeRelayerError::DuplicateMultisigSigner
+            );
+            ctx.accounts.multisig_config.signers.push(new_signer);
+        }
+        MULTISIG_ACTION_REMOVE_SIGNER => {
+            let removed_signer = Pubkey::deserialize(&mut cursor)?;
+            let index = ctx
+                .accounts
+                .multisig_config
+                .signers
+                .iter()
+                .position(|signer| *signer == removed_signer)
+                .ok_or(TokenBridgeRelayerError::MultisigSignerNotFound)?;
+            ctx.accounts.multisig_config.signers.remove(index);
+            require!(
+                ctx.accounts.multisig_config.threshold as usize
+                    <= ctx.accounts.multisig_config.signers.len(),
+                TokenBridgeRelayerError::InvalidMultisigThreshold
+            );
+        }
+        MULTISIG_ACTION_SET_THRESHOLD => {
+            let new_threshold = u8::deserialize(&mut cursor)?;
+            require!(
+                new_threshold > 0
+                    && new_threshold as usize <= ctx.accounts.multisig_config.signers.len(),
+                TokenBridgeRelayerError::InvalidMultisigThreshold
+            );
+            ctx.accounts.multisig_config.threshold = new_threshold;
+        }
+        _ => return Err(TokenBridgeRelayerError::MultisigActionTypeMismatch.into()),
+    }
+
+    ctx.accounts.pending_action.executed = true;
+
+    emit!(MultisigActionExecuted {
+        nonce: ctx.accounts.pending_action.nonce,
+        action_type
+    });
+
+    Ok(())
+}
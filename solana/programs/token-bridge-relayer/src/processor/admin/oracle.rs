@@ -0,0 +1,188 @@
+use crate::{
+    error::TokenBridgeRelayerError,
+    events::{OracleConfigChanged, SwapRateUpdatedFromOracle},
+    state::{OracleConfig, OwnerConfig, RegisteredToken},
+    SEED_PREFIX_ORACLE_CONFIG, SEED_PREFIX_REGISTERED_TOKEN, SWAP_RATE_PRECISION,
+};
+use anchor_lang::prelude::*;
+use pyth_sdk_solana::state::SolanaPriceAccount;
+
+#[derive(Accounts)]
+pub struct RegisterOracleFeed<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    #[account(seeds = [b"owner_config"], bump, has_one = owner)]
+    pub owner_config: Account<'info, OwnerConfig>,
+
+    /// CHECK: the mint the oracle feed is being registered for; only used to derive the PDA.
+    pub mint: AccountInfo<'info>,
+
+    #[account(
+        init,
+        payer = owner,
+        seeds = [SEED_PREFIX_ORACLE_CONFIG, mint.key().as_ref()],
+        bump,
+        space = OracleConfig::MAXIMUM_SIZE,
+    )]
+    pub oracle_config: Account<'info, OracleConfig>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn register_oracle_feed(
+    ctx: Context<RegisterOracleFeed>,
+    pyth_feed: Pubkey,
+    max_confidence_ratio_bps: u16,
+    max_price_age_seconds: u64,
+) -> Result<()> {
+    ctx.accounts.oracle_config.set_inner(OracleConfig {
+        pyth_feed,
+        max_confidence_ratio_bps,
+        max_price_age_seconds,
+    });
+
+    emit!(OracleConfigChanged {
+        mint: ctx.accounts.mint.key(),
+        pyth_feed,
+        max_confidence_ratio_bps,
+        max_price_age_seconds,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct UpdateOracleConfig<'info> {
+    pub owner: Signer<'info>,
+
+    #[account(seeds = [b"owner_config"], bump, has_one = owner)]
+    pub owner_config: Account<'info, OwnerConfig>,
+
+    /// CHECK: the mint whose oracle config is being updated; only used to derive the PDA.
+    pub mint: AccountInfo<'info>,
+
+    #[account(
+        mut,
+        seeds = [SEED_PREFIX_ORACLE_CONFIG, mint.key().as_ref()],
+        bump,
+    )]
+    pub oracle_config: Account<'info, OracleConfig>,
+}
+
+pub fn update_oracle_config(
+    ctx: Context<UpdateOracleConfig>,
+    pyth_feed: Pubkey,
+    max_confidence_ratio_bps: u16,
+    max_price_age_seconds: u64,
+) -> Result<()> {
+    ctx.accounts.oracle_config.pyth_feed = pyth_feed;
+    ctx.accounts.oracle_config.max_confidence_ratio_bps = max_confidence_ratio_bps;
+    ctx.accounts.oracle_config.max_price_age_seconds = max_price_age_seconds;
+
+    emit!(OracleConfigChanged {
+        mint: ctx.accounts.mint.key(),
+        pyth_feed,
+        max_confidence_ratio_bps,
+        max_price_age_seconds,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct UpdateSwapRateFromOracle<'info> {
+    pub owner: Signer<'info>,
+
+    #[account(seeds = [b"owner_config"], bump)]
+    pub owner_config: Account<'info, OwnerConfig>,
+
+    /// CHECK: the mint whose swap rate is being updated; only used to derive the PDAs below.
+    pub mint: AccountInfo<'info>,
+
+    #[account(
+        mut,
+        seeds = [SEED_PREFIX_REGISTERED_TOKEN, mint.key().as_ref()],
+        bump,
+    )]
+    pub registered_token: Account<'info, RegisteredToken>,
+
+    #[account(
+        seeds = [SEED_PREFIX_ORACLE_CONFIG, mint.key().as_ref()],
+        bump,
+    )]
+    pub oracle_config: Account<'info, OracleConfig>,
+
+    /// CHECK: verified against `oracle_config.pyth_feed` and parsed via `pyth_sdk_solana` below.
+    pub pyth_price_feed: AccountInfo<'info>,
+}
+
+pub fn update_swap_rate_from_oracle(ctx: Context<UpdateSwapRateFromOracle>) -> Result<()> {
+    require!(
+        ctx.accounts
+            .owner_config
+            .is_authorized(&ctx.accounts.owner.key()),
+        TokenBridgeRelayerError::OwnerOrAssistantOnly
+    );
+    require_keys_eq!(
+        ctx.accounts.pyth_price_feed.key(),
+        ctx.accounts.oracle_config.pyth_feed,
+        TokenBridgeRelayerError::OracleFeedMismatch
+    );
+
+    let price_feed = SolanaPriceAccount::account_info_to_feed(&ctx.accounts.pyth_price_feed)
+        .map_err(|_| TokenBridgeRelayerError::OracleFeedMismatch)?;
+
+    let current_time = Clock::get()?.unix_timestamp;
+    let price = price_feed
+        .get_price_no_older_than(
+            current_time,
+            ctx.accounts.oracle_config.max_price_age_seconds,
+        )
+        .ok_or(TokenBridgeRelayerError::OraclePriceStale)?;
+
+    require!(
+        price.price > 0,
+        TokenBridgeRelayerError::OracleNegativePrice
+    );
+
+    let confidence_ratio_bps = (price.conf as u128)
+        .checked_mul(10_000)
+        .and_then(|v| v.checked_div(price.price as u128))
+        .ok_or(TokenBridgeRelayerError::OracleConfidenceTooWide)?;
+    require!(
+        confidence_ratio_bps <= ctx.accounts.oracle_config.max_confidence_ratio_bps as u128,
+        TokenBridgeRelayerError::OracleConfidenceTooWide
+    );
+
+    // Rescales `price.price * 10^price.expo` (Pyth's fixed-point USD price) into a value scaled
+    // by `SWAP_RATE_PRECISION`, matching every other `swap_rate` write in the program.
+    // `SWAP_RATE_PRECISION` is `10^SWAP_RATE_PRECISION_EXPONENT`, checked by the const assertion
+    // below rather than computed at runtime.
+    const SWAP_RATE_PRECISION_EXPONENT: i32 = 8;
+    const _: () = assert!(SWAP_RATE_PRECISION == 10u64.pow(SWAP_RATE_PRECISION_EXPONENT as u32));
+
+    let scale_exponent = SWAP_RATE_PRECISION_EXPONENT + price.expo;
+    let scaled_price: u128 = if scale_exponent >= 0 {
+        (price.price as u128)
+            .checked_mul(10u128.pow(scale_exponent as u32))
+            .ok_or(TokenBridgeRelayerError::OracleNegativePrice)?
+    } else {
+        (price.price as u128) / 10u128.pow((-scale_exponent) as u32)
+    };
+    let new_swap_rate =
+        u64::try_from(scaled_price).map_err(|_| TokenBridgeRelayerError::OracleNegativePrice)?;
+
+    let old_swap_rate = ctx.accounts.registered_token.swap_rate;
+    ctx.accounts.registered_token.swap_rate = new_swap_rate;
+    ctx.accounts.registered_token.last_swap_rate_update = Clock::get()?.slot;
+
+    emit!(SwapRateUpdatedFromOracle {
+        mint: ctx.accounts.mint.key(),
+        old_swap_rate,
+        new_swap_rate,
+        pyth_publish_time: price.publish_time,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,221 @@
+use crate::{
+    error::TokenBridgeRelayerError,
+    events::OwnerChanged,
+    state::{
+        AdminAuditLog, AuditLogConfig, OwnerConfig, PendingAdminAction, TimelockConfig,
+        ADMIN_ACTION_CONFIRM_OWNERSHIP_TRANSFER, AUDIT_ACTION_CONFIRM_OWNERSHIP_TRANSFER,
+    },
+    SEED_PREFIX_AUDIT_LOG, SEED_PREFIX_AUDIT_LOG_CONFIG, SEED_PREFIX_PENDING_ACTION,
+    SEED_PREFIX_TIMELOCK_CONFIG,
+};
+use anchor_lang::prelude::*;
+
+fn apply_confirm_ownership_transfer(
+    owner_config: &mut Account<OwnerConfig>,
+    audit_log_config: &mut Account<AuditLogConfig>,
+    audit_log_entry: &mut Account<AdminAuditLog>,
+    pending_owner: Pubkey,
+) -> Result<()> {
+    require_keys_eq!(
+        owner_config.pending_owner.unwrap_or_default(),
+        pending_owner,
+        TokenBridgeRelayerError::OwnerOnly
+    );
+
+    let old_owner = owner_config.owner;
+    let new_owner = pending_owner;
+    owner_config.owner = new_owner;
+    owner_config.pending_owner = None;
+
+    // `old_value`/`new_value` are not meaningful here since the change is a `Pubkey`, not a
+    // `u64`; the old and new owners themselves are captured in `target` and the emitted event.
+    audit_log_entry.record(
+        AUDIT_ACTION_CONFIRM_OWNERSHIP_TRANSFER,
+        new_owner,
+        Some(old_owner),
+        0,
+        0,
+    )?;
+    audit_log_config.counter = audit_log_config
+        .counter
+        .checked_add(1)
+        .ok_or(TokenBridgeRelayerError::InsufficientFunds)?;
+
+    emit!(OwnerChanged {
+        old_owner,
+        new_owner,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct SubmitOwnershipTransferRequest<'info> {
+    pub owner: Signer<'info>,
+
+    #[account(mut, seeds = [b"owner_config"], bump, has_one = owner)]
+    pub owner_config: Account<'info, OwnerConfig>,
+}
+
+pub fn submit_ownership_transfer_request(
+    ctx: Context<SubmitOwnershipTransferRequest>,
+    new_owner: Pubkey,
+) -> Result<()> {
+    require!(
+        new_owner != Pubkey::default(),
+        TokenBridgeRelayerError::InvalidPublicKey
+    );
+
+    ctx.accounts.owner_config.pending_owner = Some(new_owner);
+    Ok(())
+}
+
+/// Confirming a transfer hands full owner control to `pending_owner`, so — like
+/// [`super::update_fee_recipient`] — it only goes through the timelocked propose/execute flow
+/// below rather than applying instantly, giving `TimelockConfig::delay_slots` for a transfer the
+/// current owner didn't intend to be noticed and cancelled via
+/// [`cancel_ownership_transfer_request`]. Takes no arguments on either side — the pending owner
+/// is read back out of `owner_config` at execution time.
+#[derive(Accounts)]
+pub struct ProposeConfirmOwnershipTransferRequest<'info> {
+    #[account(mut)]
+    pub pending_owner: Signer<'info>,
+
+    #[account(seeds = [b"owner_config"], bump)]
+    pub owner_config: Account<'info, OwnerConfig>,
+
+    #[account(
+        init_if_needed,
+        payer = pending_owner,
+        seeds = [SEED_PREFIX_TIMELOCK_CONFIG],
+        bump,
+        space = TimelockConfig::MAXIMUM_SIZE,
+    )]
+    pub timelock_config: Account<'info, TimelockConfig>,
+
+    #[account(
+        init,
+        payer = pending_owner,
+        seeds = [SEED_PREFIX_PENDING_ACTION, &timelock_config.next_action_id.to_be_bytes()],
+        bump,
+        space = PendingAdminAction::space_for(0),
+    )]
+    pub pending_action: Account<'info, PendingAdminAction>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn propose_confirm_ownership_transfer_request(
+    ctx: Context<ProposeConfirmOwnershipTransferRequest>,
+) -> Result<()> {
+    require_keys_eq!(
+        ctx.accounts.owner_config.pending_owner.unwrap_or_default(),
+        ctx.accounts.pending_owner.key(),
+        TokenBridgeRelayerError::OwnerOnly
+    );
+
+    let action_id = ctx.accounts.timelock_config.next_action_id;
+
+    ctx.accounts.pending_action.action_id = action_id;
+    ctx.accounts.pending_action.action_type = ADMIN_ACTION_CONFIRM_OWNERSHIP_TRANSFER;
+    ctx.accounts.pending_action.encoded_args = Vec::new();
+    ctx.accounts.pending_action.submitted_slot = Clock::get()?.slot;
+    ctx.accounts.pending_action.executed = false;
+
+    ctx.accounts.timelock_config.next_action_id = action_id
+        .checked_add(1)
+        .ok_or(TokenBridgeRelayerError::InsufficientFunds)?;
+
+    Ok(())
+}
+
+/// Applies a `PendingAdminAction` proposed by [`propose_confirm_ownership_transfer_request`],
+/// once `TimelockConfig::delay_slots` has elapsed since it was submitted.
+#[derive(Accounts)]
+#[instruction(action_id: u64)]
+pub struct ExecuteConfirmOwnershipTransferRequest<'info> {
+    #[account(mut)]
+    pub pending_owner: Signer<'info>,
+
+    #[account(mut, seeds = [b"owner_config"], bump)]
+    pub owner_config: Account<'info, OwnerConfig>,
+
+    #[account(seeds = [SEED_PREFIX_TIMELOCK_CONFIG], bump)]
+    pub timelock_config: Account<'info, TimelockConfig>,
+
+    #[account(
+        mut,
+        seeds = [SEED_PREFIX_PENDING_ACTION, &action_id.to_be_bytes()],
+        bump,
+    )]
+    pub pending_action: Account<'info, PendingAdminAction>,
+
+    #[account(
+        init_if_needed,
+        payer = pending_owner,
+        seeds = [SEED_PREFIX_AUDIT_LOG_CONFIG],
+        bump,
+        space = AuditLogConfig::MAXIMUM_SIZE,
+    )]
+    pub audit_log_config: Account<'info, AuditLogConfig>,
+
+    #[account(
+        init,
+        payer = pending_owner,
+        seeds = [SEED_PREFIX_AUDIT_LOG, &audit_log_config.counter.to_be_bytes()],
+        bump,
+        space = AdminAuditLog::MAXIMUM_SIZE,
+    )]
+    pub audit_log_entry: Account<'info, AdminAuditLog>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn execute_confirm_ownership_transfer_request(
+    ctx: Context<ExecuteConfirmOwnershipTransferRequest>,
+    _action_id: u64,
+) -> Result<()> {
+    require!(
+        ctx.accounts.pending_action.action_type == ADMIN_ACTION_CONFIRM_OWNERSHIP_TRANSFER,
+        TokenBridgeRelayerError::PendingActionTypeMismatch
+    );
+    require!(
+        !ctx.accounts.pending_action.executed,
+        TokenBridgeRelayerError::PendingActionAlreadyExecuted
+    );
+    require!(
+        Clock::get()?.slot
+            >= ctx
+                .accounts
+                .pending_action
+                .submitted_slot
+                .saturating_add(ctx.accounts.timelock_config.delay_slots),
+        TokenBridgeRelayerError::TimelockNotElapsed
+    );
+
+    apply_confirm_ownership_transfer(
+        &mut ctx.accounts.owner_config,
+        &mut ctx.accounts.audit_log_config,
+        &mut ctx.accounts.audit_log_entry,
+        ctx.accounts.pending_owner.key(),
+    )?;
+
+    ctx.accounts.pending_action.executed = true;
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct CancelOwnershipTransferRequest<'info> {
+    pub owner: Signer<'info>,
+
+    #[account(mut, seeds = [b"owner_config"], bump, has_one = owner)]
+    pub owner_config: Account<'info, OwnerConfig>,
+}
+
+pub fn cancel_ownership_transfer_request(
+    ctx: Context<CancelOwnershipTransferRequest>,
+) -> Result<()> {
+    ctx.accounts.owner_config.pending_owner = None;
+    Ok(())
+}
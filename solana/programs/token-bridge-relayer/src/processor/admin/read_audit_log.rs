@@ -0,0 +1,29 @@
+use crate::{events::AdminAuditLogEntryRead, state::AdminAuditLog, SEED_PREFIX_AUDIT_LOG};
+use anchor_lang::prelude::*;
+
+/// View-only instruction that emits a single `AdminAuditLog` entry as an Anchor event, so
+/// off-chain clients can read it via transaction simulation without an indexer.
+#[derive(Accounts)]
+#[instruction(counter: u64)]
+pub struct ReadAuditLog<'info> {
+    #[account(
+        seeds = [SEED_PREFIX_AUDIT_LOG, &counter.to_be_bytes()],
+        bump,
+    )]
+    pub audit_log_entry: Account<'info, AdminAuditLog>,
+}
+
+pub fn read_audit_log(ctx: Context<ReadAuditLog>, counter: u64) -> Result<()> {
+    let entry = &ctx.accounts.audit_log_entry;
+    emit!(AdminAuditLogEntryRead {
+        counter,
+        action_type: entry.action_type,
+        actor: entry.actor,
+        target: entry.target,
+        old_value: entry.old_value,
+        new_value: entry.new_value,
+        slot: entry.slot,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,62 @@
+use crate::{
+    state::{RecipientBlacklist, SenderConfig},
+    SEED_PREFIX_RECIPIENT_BLACKLIST, SEED_PREFIX_SENDER,
+};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+#[instruction(address: [u8; 32])]
+pub struct AddToBlacklist<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    #[account(
+        seeds = [SEED_PREFIX_SENDER],
+        bump = config.bump,
+        has_one = owner,
+    )]
+    pub config: Account<'info, SenderConfig>,
+
+    #[account(
+        init_if_needed,
+        payer = owner,
+        seeds = [SEED_PREFIX_RECIPIENT_BLACKLIST, address.as_ref()],
+        bump,
+        space = RecipientBlacklist::MAXIMUM_SIZE,
+    )]
+    pub blacklist_entry: Account<'info, RecipientBlacklist>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn add_to_blacklist(ctx: Context<AddToBlacklist>, address: [u8; 32]) -> Result<()> {
+    let blacklist_entry = &mut ctx.accounts.blacklist_entry;
+    blacklist_entry.address = address;
+    blacklist_entry.is_blocked = true;
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(address: [u8; 32])]
+pub struct RemoveFromBlacklist<'info> {
+    pub owner: Signer<'info>,
+
+    #[account(
+        seeds = [SEED_PREFIX_SENDER],
+        bump = config.bump,
+        has_one = owner,
+    )]
+    pub config: Account<'info, SenderConfig>,
+
+    #[account(
+        mut,
+        seeds = [SEED_PREFIX_RECIPIENT_BLACKLIST, address.as_ref()],
+        bump,
+    )]
+    pub blacklist_entry: Account<'info, RecipientBlacklist>,
+}
+
+pub fn remove_from_blacklist(ctx: Context<RemoveFromBlacklist>, _address: [u8; 32]) -> Result<()> {
+    ctx.accounts.blacklist_entry.is_blocked = false;
+    Ok(())
+}
@@ -0,0 +1,82 @@
+use crate::{
+    error::TokenBridgeRelayerError, events::TmpAccountReclaimed, state::SenderConfig,
+    SEED_PREFIX_SENDER, SEED_PREFIX_TMP,
+};
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, CloseAccount, Mint, Token, TokenAccount, Transfer};
+
+/// Recovers a `tmp_token_account` that was left with a stuck balance and unreclaimed rent
+/// after a transfer instruction failed partway through (e.g. compute budget exceeded between
+/// the token transfer into `tmp_token_account` and the Token Bridge CPI that would have moved
+/// it onward). `tmp_token_account` is a single PDA per mint shared by every payer, so this
+/// covers both a relayer-operational stuck balance and a specific payer's trapped transfer —
+/// there's no separate per-payer tmp account to rescue independently. Owner-gated so a
+/// third party can't redirect a payer's stuck balance to an arbitrary destination.
+#[derive(Accounts)]
+pub struct ReclaimTmpAccount<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    #[account(
+        seeds = [SEED_PREFIX_SENDER],
+        bump = config.bump,
+        has_one = owner,
+    )]
+    pub config: Account<'info, SenderConfig>,
+
+    pub mint: Account<'info, Mint>,
+
+    #[account(
+        mut,
+        seeds = [SEED_PREFIX_TMP, mint.key().as_ref()],
+        bump,
+        token::mint = mint,
+        token::authority = config,
+    )]
+    pub tmp_token_account: Account<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        constraint = destination_token_account.mint == mint.key() @ TokenBridgeRelayerError::FailedTransferTmpAccount,
+    )]
+    pub destination_token_account: Account<'info, TokenAccount>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+pub fn reclaim_orphaned_tmp_account(ctx: Context<ReclaimTmpAccount>) -> Result<()> {
+    let config_seeds: &[&[u8]] = &[SEED_PREFIX_SENDER, &[ctx.accounts.config.bump]];
+
+    let remaining = ctx.accounts.tmp_token_account.amount;
+    if remaining > 0 {
+        token::transfer(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.tmp_token_account.to_account_info(),
+                    to: ctx.accounts.destination_token_account.to_account_info(),
+                    authority: ctx.accounts.config.to_account_info(),
+                },
+                &[config_seeds],
+            ),
+            remaining,
+        )?;
+    }
+
+    token::close_account(CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        CloseAccount {
+            account: ctx.accounts.tmp_token_account.to_account_info(),
+            destination: ctx.accounts.owner.to_account_info(),
+            authority: ctx.accounts.config.to_account_info(),
+        },
+        &[config_seeds],
+    ))?;
+
+    emit!(TmpAccountReclaimed {
+        mint: ctx.accounts.mint.key(),
+        amount: remaining,
+    });
+
+    Ok(())
+}
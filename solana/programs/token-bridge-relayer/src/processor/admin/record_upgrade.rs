@@ -0,0 +1,57 @@
+use crate::{
+    state::{ProgramVersion, ProgramVersionConfig, SenderConfig},
+    SEED_PREFIX_PROGRAM_VERSION, SEED_PREFIX_PROGRAM_VERSION_CONFIG, SEED_PREFIX_SENDER,
+};
+use anchor_lang::prelude::*;
+
+/// Appends a new `ProgramVersion` entry after a program upgrade. Owner-only: unlike a real
+/// on-chain program upgrade (which only the upgrade authority can perform), this instruction
+/// just records that fact, so it must be gated the same way.
+#[derive(Accounts)]
+pub struct RecordUpgrade<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    #[account(
+        seeds = [SEED_PREFIX_SENDER],
+        bump = config.bump,
+        has_one = owner,
+    )]
+    pub config: Account<'info, SenderConfig>,
+
+    #[account(
+        mut,
+        seeds = [SEED_PREFIX_PROGRAM_VERSION_CONFIG],
+        bump,
+    )]
+    pub program_version_config: Account<'info, ProgramVersionConfig>,
+
+    #[account(
+        init,
+        payer = owner,
+        seeds = [SEED_PREFIX_PROGRAM_VERSION, &program_version_config.counter.to_be_bytes()],
+        bump,
+        space = ProgramVersion::MAXIMUM_SIZE,
+    )]
+    pub program_version: Account<'info, ProgramVersion>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn record_upgrade(ctx: Context<RecordUpgrade>, major: u8, minor: u8, patch: u8) -> Result<()> {
+    ctx.accounts.program_version.set_inner(ProgramVersion {
+        major,
+        minor,
+        patch,
+        deploy_slot: Clock::get()?.slot,
+        deployer: ctx.accounts.owner.key(),
+    });
+
+    ctx.accounts.program_version_config.counter = ctx
+        .accounts
+        .program_version_config
+        .counter
+        .saturating_add(1);
+
+    Ok(())
+}
@@ -0,0 +1,224 @@
+use crate::{
+    error::TokenBridgeRelayerError,
+    state::{ForeignContract, OwnerConfig, SenderConfig},
+    utils::valid_foreign_contract_address,
+    SEED_PREFIX_FOREIGN_CONTRACT, SEED_PREFIX_SENDER,
+};
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{self, CreateAccount};
+
+#[derive(Accounts)]
+#[instruction(chain: u16)]
+pub struct RegisterForeignContract<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    #[account(mut, seeds = [b"owner_config"], bump)]
+    pub owner_config: Account<'info, OwnerConfig>,
+
+    #[account(seeds = [SEED_PREFIX_SENDER], bump = config.bump)]
+    pub config: Account<'info, SenderConfig>,
+
+    #[account(
+        init_if_needed,
+        payer = owner,
+        seeds = [SEED_PREFIX_FOREIGN_CONTRACT, &chain.to_be_bytes()[..]],
+        bump,
+        space = ForeignContract::MAXIMUM_SIZE,
+    )]
+    pub foreign_contract: Account<'info, ForeignContract>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn register_foreign_contract(
+    ctx: Context<RegisterForeignContract>,
+    chain: u16,
+    address: [u8; 32],
+    relayer_fee: u64,
+) -> Result<()> {
+    require!(
+        ctx.accounts
+            .owner_config
+            .is_authorized(&ctx.accounts.owner.key()),
+        TokenBridgeRelayerError::OwnerOrAssistantOnly
+    );
+    require!(
+        ForeignContract::is_valid_address(&address),
+        TokenBridgeRelayerError::InvalidPublicKey
+    );
+
+    let is_new_registration =
+        !ForeignContract::is_valid_address(&ctx.accounts.foreign_contract.address);
+    if is_new_registration {
+        require!(
+            ctx.accounts.owner_config.registered_contract_count
+                < ctx.accounts.owner_config.max_foreign_contracts,
+            TokenBridgeRelayerError::TooManyForeignContracts
+        );
+        ctx.accounts.owner_config.registered_contract_count = ctx
+            .accounts
+            .owner_config
+            .registered_contract_count
+            .saturating_add(1);
+    }
+
+    let token_bridge_foreign_endpoint = ctx.accounts.foreign_contract.token_bridge_foreign_endpoint;
+
+    // `token_bridge_foreign_endpoint` isn't a parameter here — it's carried over unchanged from
+    // whatever `update_foreign_endpoint` last set it to (or left as its zeroed default for a
+    // brand new registration, in which case there's nothing to cross-check yet). Once it has been
+    // set, though, it and `address` are supposed to name the same emitter (see their doc
+    // comments on `ForeignContract`); catch the two drifting apart, e.g. an emitter address
+    // rotated via this instruction without a matching `update_foreign_endpoint` call. The PDA
+    // seeds already tie this account to `chain`, so there's no separate chain field to compare.
+    require!(
+        !ForeignContract::is_valid_address(&token_bridge_foreign_endpoint)
+            || token_bridge_foreign_endpoint == address,
+        TokenBridgeRelayerError::EndpointAddressMismatch
+    );
+
+    // Carried over unchanged, same reasoning as `token_bridge_foreign_endpoint` above: this
+    // instruction doesn't take a `fee_in_token_units` parameter, so re-registering an existing
+    // chain shouldn't silently reset a denomination mode set via `set_fee_denomination_mode`.
+    let fee_in_token_units = ctx.accounts.foreign_contract.fee_in_token_units;
+
+    // Same reasoning again: `fee_native_token_amount` is only settable via
+    // `update_fee_native_token`, so re-registering an existing chain shouldn't silently clear a
+    // gas-cost floor that was already set for it.
+    let fee_native_token_amount = ctx.accounts.foreign_contract.fee_native_token_amount;
+
+    ctx.accounts.foreign_contract.set_inner(ForeignContract {
+        chain,
+        address,
+        token_bridge_foreign_endpoint,
+        fee: relayer_fee,
+        min_fee: 0,
+        max_fee: 0,
+        is_active: true,
+        fee_in_token_units,
+        fee_native_token_amount,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct RegisterForeignContractsBatch<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    #[account(mut, seeds = [b"owner_config"], bump)]
+    pub owner_config: Account<'info, OwnerConfig>,
+
+    #[account(seeds = [SEED_PREFIX_SENDER], bump = config.bump)]
+    pub config: Account<'info, SenderConfig>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Registers or updates many `ForeignContract`s in one transaction, so onboarding a new
+/// deployment doesn't need one `register_foreign_contract` call per supported chain. `entries`
+/// (`(chain, address, relayer_fee)`) must line up one-to-one with `ctx.remaining_accounts`, each
+/// of which is that chain's `[SEED_PREFIX_FOREIGN_CONTRACT, chain.to_be_bytes()]` PDA. An account
+/// that doesn't exist yet is created in place via a manual `system_program::create_account` CPI
+/// signed with its own PDA seeds, since `remaining_accounts` can't go through Anchor's
+/// `init_if_needed` the way `register_foreign_contract`'s single `foreign_contract` account does.
+pub fn register_foreign_contracts_batch<'info>(
+    ctx: Context<'_, '_, '_, 'info, RegisterForeignContractsBatch<'info>>,
+    entries: Vec<(u16, [u8; 32], u64)>,
+) -> Result<()> {
+    require!(
+        ctx.accounts
+            .owner_config
+            .is_authorized(&ctx.accounts.owner.key()),
+        TokenBridgeRelayerError::OwnerOrAssistantOnly
+    );
+    require!(
+        ctx.remaining_accounts.len() == entries.len(),
+        TokenBridgeRelayerError::BatchLengthMismatch
+    );
+
+    for (foreign_contract_info, &(chain, address, relayer_fee)) in
+        ctx.remaining_accounts.iter().zip(entries.iter())
+    {
+        require!(
+            valid_foreign_contract_address(&address),
+            TokenBridgeRelayerError::InvalidPublicKey
+        );
+
+        let (expected_pda, bump) = Pubkey::find_program_address(
+            &[SEED_PREFIX_FOREIGN_CONTRACT, &chain.to_be_bytes()[..]],
+            ctx.program_id,
+        );
+        require!(
+            expected_pda == foreign_contract_info.key(),
+            TokenBridgeRelayerError::InvalidForeignContractAccount
+        );
+
+        let is_new_registration = foreign_contract_info.data_is_empty();
+        if is_new_registration {
+            require!(
+                ctx.accounts.owner_config.registered_contract_count
+                    < ctx.accounts.owner_config.max_foreign_contracts,
+                TokenBridgeRelayerError::TooManyForeignContracts
+            );
+            ctx.accounts.owner_config.registered_contract_count = ctx
+                .accounts
+                .owner_config
+                .registered_contract_count
+                .saturating_add(1);
+
+            let lamports = Rent::get()?.minimum_balance(ForeignContract::MAXIMUM_SIZE);
+            let bump_seed = [bump];
+            let seeds: &[&[u8]] = &[
+                SEED_PREFIX_FOREIGN_CONTRACT,
+                &chain.to_be_bytes()[..],
+                &bump_seed,
+            ];
+            system_program::create_account(
+                CpiContext::new_with_signer(
+                    ctx.accounts.system_program.to_account_info(),
+                    CreateAccount {
+                        from: ctx.accounts.owner.to_account_info(),
+                        to: foreign_contract_info.clone(),
+                    },
+                    &[seeds],
+                ),
+                lamports,
+                ForeignContract::MAXIMUM_SIZE as u64,
+                ctx.program_id,
+            )?;
+        }
+
+        let mut foreign_contract = if is_new_registration {
+            Account::<ForeignContract>::try_from_unchecked(foreign_contract_info)?
+        } else {
+            Account::<ForeignContract>::try_from(foreign_contract_info)
+                .map_err(|_| TokenBridgeRelayerError::InvalidForeignContractAccount)?
+        };
+
+        let token_bridge_foreign_endpoint = foreign_contract.token_bridge_foreign_endpoint;
+        require!(
+            !ForeignContract::is_valid_address(&token_bridge_foreign_endpoint)
+                || token_bridge_foreign_endpoint == address,
+            TokenBridgeRelayerError::EndpointAddressMismatch
+        );
+        let fee_in_token_units = foreign_contract.fee_in_token_units;
+        let fee_native_token_amount = foreign_contract.fee_native_token_amount;
+        foreign_contract.set_inner(ForeignContract {
+            chain,
+            address,
+            token_bridge_foreign_endpoint,
+            fee: relayer_fee,
+            min_fee: 0,
+            max_fee: 0,
+            is_active: true,
+            fee_in_token_units,
+            fee_native_token_amount,
+        });
+        foreign_contract.exit(ctx.program_id)?;
+    }
+
+    Ok(())
+}
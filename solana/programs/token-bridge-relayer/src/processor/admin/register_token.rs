@@ -0,0 +1,120 @@
+use crate::{
+    constants::MIN_SWAP_RATE_FOR_LOW_DECIMAL_TOKEN,
+    error::TokenBridgeRelayerError,
+    events::{TokenRegisteredWithLowDecimals, TokenRegistrationChanged},
+    state::{OwnerConfig, RegisteredToken, SenderConfig, TokenRegistry},
+    SEED_PREFIX_REGISTERED_TOKEN, SEED_PREFIX_SENDER, SEED_PREFIX_TOKEN_REGISTRY,
+};
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::Mint;
+
+#[derive(Accounts)]
+pub struct RegisterToken<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    #[account(
+        seeds = [SEED_PREFIX_SENDER],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, SenderConfig>,
+
+    #[account(mut, seeds = [b"owner_config"], bump)]
+    pub owner_config: Account<'info, OwnerConfig>,
+
+    /// Accepted from either classic SPL Token or Token-2022; `InterfaceAccount` rejects any mint
+    /// not owned by one of those two programs, so no separate owner check is needed here.
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        init,
+        payer = owner,
+        seeds = [SEED_PREFIX_REGISTERED_TOKEN, mint.key().as_ref()],
+        bump,
+        space = RegisteredToken::MAXIMUM_SIZE,
+    )]
+    pub registered_token: Account<'info, RegisteredToken>,
+
+    #[account(
+        mut,
+        seeds = [SEED_PREFIX_TOKEN_REGISTRY],
+        bump,
+        realloc = TokenRegistry::space_for(token_registry.mints.len() + 1),
+        realloc::payer = owner,
+        realloc::zero = false,
+    )]
+    pub token_registry: Account<'info, TokenRegistry>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn register_token(
+    ctx: Context<RegisterToken>,
+    swap_rate: u64,
+    max_native_swap_amount: u64,
+    enable_swap: bool,
+) -> Result<()> {
+    require!(
+        ctx.accounts
+            .owner_config
+            .is_authorized(&ctx.accounts.owner.key()),
+        TokenBridgeRelayerError::OwnerOrAssistantOnly
+    );
+    require!(
+        swap_rate > 0,
+        TokenBridgeRelayerError::NonexistentRelayerFee
+    );
+    require!(
+        swap_rate
+            >= RegisteredToken::min_valid_swap_rate(
+                ctx.accounts
+                    .registered_token
+                    .effective_swap_rate_precision()
+            ),
+        TokenBridgeRelayerError::SwapRateTooLow
+    );
+    if ctx.accounts.mint.decimals < 3 {
+        require!(
+            swap_rate >= MIN_SWAP_RATE_FOR_LOW_DECIMAL_TOKEN,
+            TokenBridgeRelayerError::SwapRateImplausibleForDecimals
+        );
+    }
+    require!(
+        ctx.accounts.owner_config.registered_token_count
+            < ctx.accounts.owner_config.max_registered_tokens,
+        TokenBridgeRelayerError::MaxTokensRegistered
+    );
+    ctx.accounts.owner_config.registered_token_count = ctx
+        .accounts
+        .owner_config
+        .registered_token_count
+        .saturating_add(1);
+
+    let registered_token = &mut ctx.accounts.registered_token;
+    **registered_token = RegisteredToken::new(
+        swap_rate,
+        max_native_swap_amount,
+        ctx.accounts.mint.decimals,
+        enable_swap,
+        Clock::get()?.slot,
+    );
+
+    ctx.accounts
+        .token_registry
+        .mints
+        .push(ctx.accounts.mint.key());
+
+    if ctx.accounts.mint.decimals < 6 {
+        emit!(TokenRegisteredWithLowDecimals {
+            mint: ctx.accounts.mint.key(),
+            decimals: ctx.accounts.mint.decimals,
+        });
+    }
+
+    emit!(TokenRegistrationChanged {
+        mint: ctx.accounts.mint.key(),
+        is_registered: true,
+    });
+
+    Ok(())
+}
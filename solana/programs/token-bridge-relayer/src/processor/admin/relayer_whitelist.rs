@@ -0,0 +1,86 @@
+use crate::{
+    state::{RedeemerConfig, RelayerWhitelist},
+    SEED_PREFIX_REDEEMER, SEED_PREFIX_RELAYER_WHITELIST,
+};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+#[instruction(relayer: Pubkey)]
+pub struct AddWhitelistedRelayer<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    #[account(
+        seeds = [SEED_PREFIX_REDEEMER],
+        bump = config.bump,
+        has_one = owner,
+    )]
+    pub config: Account<'info, RedeemerConfig>,
+
+    #[account(
+        init_if_needed,
+        payer = owner,
+        seeds = [SEED_PREFIX_RELAYER_WHITELIST, relayer.as_ref()],
+        bump,
+        space = RelayerWhitelist::MAXIMUM_SIZE,
+    )]
+    pub relayer_whitelist: Account<'info, RelayerWhitelist>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn add_whitelisted_relayer(ctx: Context<AddWhitelistedRelayer>, relayer: Pubkey) -> Result<()> {
+    let relayer_whitelist = &mut ctx.accounts.relayer_whitelist;
+    relayer_whitelist.relayer = relayer;
+    relayer_whitelist.is_allowed = true;
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(relayer: Pubkey)]
+pub struct RemoveWhitelistedRelayer<'info> {
+    pub owner: Signer<'info>,
+
+    #[account(
+        seeds = [SEED_PREFIX_REDEEMER],
+        bump = config.bump,
+        has_one = owner,
+    )]
+    pub config: Account<'info, RedeemerConfig>,
+
+    #[account(
+        mut,
+        seeds = [SEED_PREFIX_RELAYER_WHITELIST, relayer.as_ref()],
+        bump,
+    )]
+    pub relayer_whitelist: Account<'info, RelayerWhitelist>,
+}
+
+pub fn remove_whitelisted_relayer(
+    ctx: Context<RemoveWhitelistedRelayer>,
+    _relayer: Pubkey,
+) -> Result<()> {
+    ctx.accounts.relayer_whitelist.is_allowed = false;
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct SetRelayerWhitelistEnabled<'info> {
+    pub owner: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [SEED_PREFIX_REDEEMER],
+        bump = config.bump,
+        has_one = owner,
+    )]
+    pub config: Account<'info, RedeemerConfig>,
+}
+
+pub fn set_relayer_whitelist_enabled(
+    ctx: Context<SetRelayerWhitelistEnabled>,
+    enabled: bool,
+) -> Result<()> {
+    ctx.accounts.config.whitelist_enabled = enabled;
+    Ok(())
+}
@@ -0,0 +1,31 @@
+use crate::{
+    state::{ChainStats, SenderConfig},
+    SEED_PREFIX_CHAIN_STATS, SEED_PREFIX_SENDER,
+};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+#[instruction(chain: u16)]
+pub struct ResetChainStats<'info> {
+    pub owner: Signer<'info>,
+
+    #[account(
+        seeds = [SEED_PREFIX_SENDER],
+        bump = config.bump,
+        has_one = owner,
+    )]
+    pub config: Account<'info, SenderConfig>,
+
+    #[account(
+        mut,
+        seeds = [SEED_PREFIX_CHAIN_STATS, &chain.to_le_bytes()[..]],
+        bump,
+    )]
+    pub chain_stats: Account<'info, ChainStats>,
+}
+
+pub fn reset_chain_stats(ctx: Context<ResetChainStats>, _chain: u16) -> Result<()> {
+    ctx.accounts.chain_stats.transfers_in = 0;
+    ctx.accounts.chain_stats.transfers_out = 0;
+    Ok(())
+}
@@ -0,0 +1,33 @@
+use crate::{
+    events::CircuitBreakerReset, state::CircuitBreaker, state::OwnerConfig,
+    SEED_PREFIX_CIRCUIT_BREAKER,
+};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct ResetCircuitBreaker<'info> {
+    pub owner: Signer<'info>,
+
+    #[account(seeds = [b"owner_config"], bump, has_one = owner)]
+    pub owner_config: Account<'info, OwnerConfig>,
+
+    #[account(
+        mut,
+        seeds = [SEED_PREFIX_CIRCUIT_BREAKER],
+        bump,
+    )]
+    pub circuit_breaker: Account<'info, CircuitBreaker>,
+}
+
+/// Clears `tripped` and resets the volume window, restoring normal outbound transfer operation.
+/// Owner-only: resuming transfers after a suspected exploit shouldn't be delegable to the
+/// assistant key.
+pub fn reset_circuit_breaker(ctx: Context<ResetCircuitBreaker>) -> Result<()> {
+    ctx.accounts.circuit_breaker.tripped = false;
+    ctx.accounts.circuit_breaker.volume_this_window = 0;
+    ctx.accounts.circuit_breaker.window_start_slot = Clock::get()?.slot;
+
+    emit!(CircuitBreakerReset {});
+
+    Ok(())
+}
@@ -0,0 +1,33 @@
+use crate::{
+    state::{RegisteredToken, SenderConfig},
+    SEED_PREFIX_REGISTERED_TOKEN, SEED_PREFIX_SENDER,
+};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct ResetCumulativeVolume<'info> {
+    pub owner: Signer<'info>,
+
+    #[account(
+        seeds = [SEED_PREFIX_SENDER],
+        bump = config.bump,
+        has_one = owner,
+    )]
+    pub config: Account<'info, SenderConfig>,
+
+    /// CHECK: the mint whose volume counters are being reset; only used to derive the PDA.
+    pub mint: AccountInfo<'info>,
+
+    #[account(
+        mut,
+        seeds = [SEED_PREFIX_REGISTERED_TOKEN, mint.key().as_ref()],
+        bump,
+    )]
+    pub registered_token: Account<'info, RegisteredToken>,
+}
+
+pub fn reset_cumulative_volume(ctx: Context<ResetCumulativeVolume>) -> Result<()> {
+    ctx.accounts.registered_token.cumulative_volume_in = 0;
+    ctx.accounts.registered_token.cumulative_volume_out = 0;
+    Ok(())
+}
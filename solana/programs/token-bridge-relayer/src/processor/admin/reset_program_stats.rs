@@ -0,0 +1,30 @@
+use crate::{
+    state::{ProgramStats, SenderConfig},
+    SEED_PREFIX_PROGRAM_STATS, SEED_PREFIX_SENDER,
+};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct ResetProgramStats<'info> {
+    pub owner: Signer<'info>,
+
+    #[account(
+        seeds = [SEED_PREFIX_SENDER],
+        bump = config.bump,
+        has_one = owner,
+    )]
+    pub config: Account<'info, SenderConfig>,
+
+    #[account(
+        mut,
+        seeds = [SEED_PREFIX_PROGRAM_STATS],
+        bump,
+    )]
+    pub program_stats: Account<'info, ProgramStats>,
+}
+
+pub fn reset_program_stats(ctx: Context<ResetProgramStats>) -> Result<()> {
+    ctx.accounts.program_stats.total_transfers_in = 0;
+    ctx.accounts.program_stats.total_transfers_out = 0;
+    Ok(())
+}
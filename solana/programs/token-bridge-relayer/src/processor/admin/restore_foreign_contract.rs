@@ -0,0 +1,15 @@
+use crate::processor::admin::{register_foreign_contract, RegisterForeignContract};
+use anchor_lang::prelude::*;
+
+/// Re-creates a `ForeignContract` registration previously removed with
+/// `close_foreign_contract`, using the same PDA seeds. Functionally identical to
+/// `register_foreign_contract`; kept as a distinct entry point so restoring a deprecated chain
+/// reads clearly in transaction history.
+pub fn restore_foreign_contract(
+    ctx: Context<RegisterForeignContract>,
+    chain: u16,
+    address: [u8; 32],
+    relayer_fee: u64,
+) -> Result<()> {
+    register_foreign_contract(ctx, chain, address, relayer_fee)
+}
@@ -0,0 +1,71 @@
+use crate::{error::TokenBridgeRelayerError, state::SenderConfig, SEED_PREFIX_SENDER};
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::{
+    bpf_loader_upgradeable, bpf_loader_upgradeable::UpgradeableLoaderState, program::invoke,
+};
+
+/// Permanently revokes this program's upgrade authority. Irreversible, and left to the owner to
+/// call explicitly (e.g. once a devnet deployment has soaked and is ready to be locked down)
+/// rather than firing automatically inside `initialize`, since folding it into account setup gave
+/// every non-devnet deployer a single instruction that both creates the program's config accounts
+/// and permanently locks the program, with no way to do the former without the latter.
+#[derive(Accounts)]
+pub struct RevokeUpgradeAuthority<'info> {
+    pub owner: Signer<'info>,
+
+    #[account(
+        seeds = [SEED_PREFIX_SENDER],
+        bump = config.bump,
+        has_one = owner,
+    )]
+    pub config: Account<'info, SenderConfig>,
+
+    /// CHECK: this program's own `ProgramData` account, as derived by the BPF upgradeable
+    /// loader. Read back after `set_upgrade_authority` below to confirm the upgrade authority
+    /// was actually cleared, in case a wrong account or a silently-failed CPI would otherwise
+    /// leave the program upgradeable without anyone noticing.
+    #[account(
+        mut,
+        address = Pubkey::find_program_address(&[crate::ID.as_ref()], &bpf_loader_upgradeable::ID).0,
+    )]
+    pub program_data: AccountInfo<'info>,
+
+    /// CHECK: address-constrained to the BPF upgradeable loader; only used as the CPI target
+    /// below for revoking this program's upgrade authority.
+    #[account(address = bpf_loader_upgradeable::ID)]
+    pub bpf_loader_upgradeable_program: AccountInfo<'info>,
+}
+
+pub fn revoke_upgrade_authority(ctx: Context<RevokeUpgradeAuthority>) -> Result<()> {
+    invoke(
+        &bpf_loader_upgradeable::set_upgrade_authority(&crate::ID, &ctx.accounts.owner.key(), None),
+        &[
+            ctx.accounts.program_data.to_account_info(),
+            ctx.accounts.owner.to_account_info(),
+            ctx.accounts
+                .bpf_loader_upgradeable_program
+                .to_account_info(),
+        ],
+    )?;
+
+    // `set_upgrade_authority` succeeding doesn't, by itself, guarantee the authority is actually
+    // gone (a wrong `program_data` account would satisfy the CPI's own account checks while
+    // pointing at the wrong program's state). Re-read `program_data` to confirm the authority
+    // the loader now reports is really `None`.
+    let programdata_state: UpgradeableLoaderState =
+        bincode::deserialize(&ctx.accounts.program_data.try_borrow_data()?)
+            .map_err(|_| TokenBridgeRelayerError::FailedToMakeImmutable)?;
+    let upgrade_authority_address = match programdata_state {
+        UpgradeableLoaderState::ProgramData {
+            upgrade_authority_address,
+            ..
+        } => upgrade_authority_address,
+        _ => return Err(TokenBridgeRelayerError::FailedToMakeImmutable.into()),
+    };
+    require!(
+        upgrade_authority_address.is_none(),
+        TokenBridgeRelayerError::FailedToMakeImmutable
+    );
+
+    Ok(())
+}
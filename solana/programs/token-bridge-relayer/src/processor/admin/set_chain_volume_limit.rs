@@ -0,0 +1,50 @@
+use crate::{
+    events::ChainVolumeLimitChanged,
+    state::{ChainVolumeLimit, SenderConfig},
+    SEED_PREFIX_CHAIN_VOLUME_LIMIT, SEED_PREFIX_SENDER,
+};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+#[instruction(chain: u16)]
+pub struct SetChainVolumeLimit<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    #[account(
+        seeds = [SEED_PREFIX_SENDER],
+        bump = config.bump,
+        has_one = owner,
+    )]
+    pub config: Account<'info, SenderConfig>,
+
+    #[account(
+        init_if_needed,
+        payer = owner,
+        seeds = [SEED_PREFIX_CHAIN_VOLUME_LIMIT, &chain.to_be_bytes()[..]],
+        bump,
+        space = ChainVolumeLimit::MAXIMUM_SIZE,
+    )]
+    pub chain_volume_limit: Account<'info, ChainVolumeLimit>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn set_chain_volume_limit(
+    ctx: Context<SetChainVolumeLimit>,
+    chain: u16,
+    daily_limit: u64,
+    slots_per_window: u64,
+) -> Result<()> {
+    ctx.accounts.chain_volume_limit.chain = chain;
+    ctx.accounts.chain_volume_limit.daily_limit = daily_limit;
+    ctx.accounts.chain_volume_limit.slots_per_window = slots_per_window;
+
+    emit!(ChainVolumeLimitChanged {
+        chain,
+        daily_limit,
+        slots_per_window,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,56 @@
+use crate::{
+    events::EpochFeeScheduleChanged,
+    state::{EpochFeeSchedule, SenderConfig},
+    SEED_PREFIX_EPOCH_FEE_SCHEDULE, SEED_PREFIX_SENDER,
+};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+#[instruction(chain: u16)]
+pub struct SetEpochFeeSchedule<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    #[account(
+        seeds = [SEED_PREFIX_SENDER],
+        bump = config.bump,
+        has_one = owner,
+    )]
+    pub config: Account<'info, SenderConfig>,
+
+    #[account(
+        init_if_needed,
+        payer = owner,
+        seeds = [SEED_PREFIX_EPOCH_FEE_SCHEDULE, &chain.to_be_bytes()[..]],
+        bump,
+        space = EpochFeeSchedule::MAXIMUM_SIZE,
+    )]
+    pub epoch_fee_schedule: Account<'info, EpochFeeSchedule>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn set_epoch_fee_schedule(
+    ctx: Context<SetEpochFeeSchedule>,
+    chain: u16,
+    base_fee: u64,
+    promo_fee: u64,
+    promo_start_slot: u64,
+    promo_end_slot: u64,
+) -> Result<()> {
+    ctx.accounts.epoch_fee_schedule.chain = chain;
+    ctx.accounts.epoch_fee_schedule.base_fee = base_fee;
+    ctx.accounts.epoch_fee_schedule.promo_fee = promo_fee;
+    ctx.accounts.epoch_fee_schedule.promo_start_slot = promo_start_slot;
+    ctx.accounts.epoch_fee_schedule.promo_end_slot = promo_end_slot;
+
+    emit!(EpochFeeScheduleChanged {
+        chain,
+        base_fee,
+        promo_fee,
+        promo_start_slot,
+        promo_end_slot,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,45 @@
+use crate::{
+    events::FeeDenominationModeChanged,
+    state::{ForeignContract, SenderConfig},
+    SEED_PREFIX_FOREIGN_CONTRACT, SEED_PREFIX_SENDER,
+};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+#[instruction(chain: u16)]
+pub struct SetFeeDenominationMode<'info> {
+    pub owner: Signer<'info>,
+
+    #[account(
+        seeds = [SEED_PREFIX_SENDER],
+        bump = config.bump,
+        has_one = owner,
+    )]
+    pub config: Account<'info, SenderConfig>,
+
+    #[account(
+        mut,
+        seeds = [SEED_PREFIX_FOREIGN_CONTRACT, &chain.to_be_bytes()[..]],
+        bump,
+    )]
+    pub foreign_contract: Account<'info, ForeignContract>,
+}
+
+/// Switches whether `foreign_contract.fee` is a USD amount (converted to token units via
+/// `ForeignContract::checked_usd_to_token_amount` on every transfer) or is already a raw token
+/// amount (used as-is). Doesn't touch `foreign_contract.fee` itself, so the owner should follow
+/// up with `update_relayer_fee` if the existing value needs rescaling for the new mode.
+pub fn set_fee_denomination_mode(
+    ctx: Context<SetFeeDenominationMode>,
+    chain: u16,
+    fee_in_token_units: bool,
+) -> Result<()> {
+    ctx.accounts.foreign_contract.fee_in_token_units = fee_in_token_units;
+
+    emit!(FeeDenominationModeChanged {
+        chain,
+        fee_in_token_units,
+    });
+
+    Ok(())
+}
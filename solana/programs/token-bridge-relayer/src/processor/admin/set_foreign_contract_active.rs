@@ -0,0 +1,38 @@
+use crate::{
+    events::ForeignContractActiveChanged,
+    state::{ForeignContract, SenderConfig},
+    SEED_PREFIX_FOREIGN_CONTRACT, SEED_PREFIX_SENDER,
+};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+#[instruction(chain: u16)]
+pub struct SetForeignContractActive<'info> {
+    pub owner: Signer<'info>,
+
+    #[account(
+        seeds = [SEED_PREFIX_SENDER],
+        bump = config.bump,
+        has_one = owner,
+    )]
+    pub config: Account<'info, SenderConfig>,
+
+    #[account(
+        mut,
+        seeds = [SEED_PREFIX_FOREIGN_CONTRACT, &chain.to_be_bytes()[..]],
+        bump,
+    )]
+    pub foreign_contract: Account<'info, ForeignContract>,
+}
+
+pub fn set_foreign_contract_active(
+    ctx: Context<SetForeignContractActive>,
+    chain: u16,
+    is_active: bool,
+) -> Result<()> {
+    ctx.accounts.foreign_contract.is_active = is_active;
+
+    emit!(ForeignContractActiveChanged { chain, is_active });
+
+    Ok(())
+}
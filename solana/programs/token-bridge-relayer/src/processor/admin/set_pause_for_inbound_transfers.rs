@@ -0,0 +1,26 @@
+use crate::{events::InboundTransfersPauseToggled, state::RedeemerConfig, SEED_PREFIX_REDEEMER};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct SetPauseForInboundTransfers<'info> {
+    pub owner: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [SEED_PREFIX_REDEEMER],
+        bump = config.bump,
+        has_one = owner,
+    )]
+    pub config: Account<'info, RedeemerConfig>,
+}
+
+pub fn set_pause_for_inbound_transfers(
+    ctx: Context<SetPauseForInboundTransfers>,
+    paused: bool,
+) -> Result<()> {
+    ctx.accounts.config.inbound_paused = paused;
+
+    emit!(InboundTransfersPauseToggled { paused });
+
+    Ok(())
+}
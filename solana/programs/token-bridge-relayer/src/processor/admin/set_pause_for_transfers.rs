@@ -0,0 +1,48 @@
+use crate::{
+    error::TokenBridgeRelayerError,
+    events::TransfersPauseToggled,
+    state::{OwnerConfig, SenderConfig},
+    SEED_PREFIX_SENDER,
+};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct SetPauseForTransfers<'info> {
+    pub owner: Signer<'info>,
+
+    #[account(seeds = [b"owner_config"], bump)]
+    pub owner_config: Account<'info, OwnerConfig>,
+
+    #[account(
+        mut,
+        seeds = [SEED_PREFIX_SENDER],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, SenderConfig>,
+}
+
+/// Pausing is delegated to the assistant, same as the other operational-parameter updates in
+/// this module, but unpausing is kept owner-only: an assistant key with a milder compromise
+/// shouldn't be able to unilaterally resume outbound transfers it (or an attacker) just paused.
+pub fn set_pause_for_transfers(ctx: Context<SetPauseForTransfers>, paused: bool) -> Result<()> {
+    if paused {
+        require!(
+            ctx.accounts
+                .owner_config
+                .is_authorized(&ctx.accounts.owner.key()),
+            TokenBridgeRelayerError::OwnerOrAssistantOnly
+        );
+    } else {
+        require_keys_eq!(
+            ctx.accounts.owner.key(),
+            ctx.accounts.owner_config.owner,
+            TokenBridgeRelayerError::OwnerOnly
+        );
+    }
+
+    ctx.accounts.config.paused = paused;
+
+    emit!(TransfersPauseToggled { paused });
+
+    Ok(())
+}
@@ -0,0 +1,32 @@
+use crate::{
+    state::{OwnerConfig, TimelockConfig},
+    SEED_PREFIX_TIMELOCK_CONFIG,
+};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct SetTimelockDelay<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    #[account(seeds = [b"owner_config"], bump, has_one = owner)]
+    pub owner_config: Account<'info, OwnerConfig>,
+
+    #[account(
+        init_if_needed,
+        payer = owner,
+        seeds = [SEED_PREFIX_TIMELOCK_CONFIG],
+        bump,
+        space = TimelockConfig::MAXIMUM_SIZE,
+    )]
+    pub timelock_config: Account<'info, TimelockConfig>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Sets how many slots a `PendingAdminAction` must sit before `execute_*` will replay it. `0`
+/// disables the delay, letting `execute_*` run in the same slot it was proposed in.
+pub fn set_timelock_delay(ctx: Context<SetTimelockDelay>, delay_slots: u64) -> Result<()> {
+    ctx.accounts.timelock_config.delay_slots = delay_slots;
+    Ok(())
+}
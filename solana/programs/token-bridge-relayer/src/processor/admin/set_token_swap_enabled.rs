@@ -0,0 +1,32 @@
+use crate::{
+    state::{RegisteredToken, SenderConfig},
+    SEED_PREFIX_REGISTERED_TOKEN, SEED_PREFIX_SENDER,
+};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct SetTokenSwapEnabled<'info> {
+    pub owner: Signer<'info>,
+
+    #[account(
+        seeds = [SEED_PREFIX_SENDER],
+        bump = config.bump,
+        has_one = owner,
+    )]
+    pub config: Account<'info, SenderConfig>,
+
+    /// CHECK: the mint whose swap-enabled flag is being updated; only used to derive the PDA.
+    pub mint: AccountInfo<'info>,
+
+    #[account(
+        mut,
+        seeds = [SEED_PREFIX_REGISTERED_TOKEN, mint.key().as_ref()],
+        bump,
+    )]
+    pub registered_token: Account<'info, RegisteredToken>,
+}
+
+pub fn set_token_swap_enabled(ctx: Context<SetTokenSwapEnabled>, enabled: bool) -> Result<()> {
+    ctx.accounts.registered_token.swap_enabled = enabled;
+    Ok(())
+}
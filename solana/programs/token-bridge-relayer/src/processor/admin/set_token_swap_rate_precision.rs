@@ -0,0 +1,153 @@
+use crate::{
+    error::TokenBridgeRelayerError,
+    state::{
+        OwnerConfig, PendingAdminAction, RegisteredToken, TimelockConfig,
+        ADMIN_ACTION_SET_TOKEN_SWAP_RATE_PRECISION,
+    },
+    SEED_PREFIX_PENDING_ACTION, SEED_PREFIX_REGISTERED_TOKEN, SEED_PREFIX_TIMELOCK_CONFIG,
+};
+use anchor_lang::prelude::*;
+
+/// Upper bound on `PendingAdminAction::encoded_args` for this instruction: the target mint plus
+/// a `u32` override.
+const SWAP_RATE_PRECISION_ARGS_SIZE: usize = 32 + 4;
+
+fn apply_swap_rate_precision_override(
+    registered_token: &mut Account<RegisteredToken>,
+    swap_rate_precision_override: u32,
+) {
+    registered_token.swap_rate_precision_override = swap_rate_precision_override;
+}
+
+/// Overrides the swap rate precision used for one token's `swap_rate` scaling, so
+/// `calculate_native_swap_amounts` scales this token's `swap_rate` at a different precision than
+/// the global [`crate::SWAP_RATE_PRECISION`]. `0` reverts the token to the global precision.
+/// Owner-only, unlike most other per-token parameter updates: a wrong precision silently
+/// corrupts every native-swap calculation for this token rather than failing loudly. For the
+/// same reason it only goes through the timelocked propose/execute flow below rather than
+/// applying instantly, like [`super::update_fee_recipient`].
+#[derive(Accounts)]
+pub struct ProposeSetTokenSwapRatePrecision<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    #[account(seeds = [b"owner_config"], bump, has_one = owner)]
+    pub owner_config: Account<'info, OwnerConfig>,
+
+    /// CHECK: the mint being updated; only used to derive the PDA.
+    pub mint: AccountInfo<'info>,
+
+    #[account(
+        seeds = [SEED_PREFIX_REGISTERED_TOKEN, mint.key().as_ref()],
+        bump,
+    )]
+    pub registered_token: Account<'info, RegisteredToken>,
+
+    #[account(
+        init_if_needed,
+        payer = owner,
+        seeds = [SEED_PREFIX_TIMELOCK_CONFIG],
+        bump,
+        space = TimelockConfig::MAXIMUM_SIZE,
+    )]
+    pub timelock_config: Account<'info, TimelockConfig>,
+
+    #[account(
+        init,
+        payer = owner,
+        seeds = [SEED_PREFIX_PENDING_ACTION, &timelock_config.next_action_id.to_be_bytes()],
+        bump,
+        space = PendingAdminAction::space_for(SWAP_RATE_PRECISION_ARGS_SIZE),
+    )]
+    pub pending_action: Account<'info, PendingAdminAction>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn propose_set_token_swap_rate_precision(
+    ctx: Context<ProposeSetTokenSwapRatePrecision>,
+    swap_rate_precision_override: u32,
+) -> Result<()> {
+    let action_id = ctx.accounts.timelock_config.next_action_id;
+
+    ctx.accounts.pending_action.action_id = action_id;
+    ctx.accounts.pending_action.action_type = ADMIN_ACTION_SET_TOKEN_SWAP_RATE_PRECISION;
+    ctx.accounts.pending_action.encoded_args =
+        (ctx.accounts.mint.key(), swap_rate_precision_override).try_to_vec()?;
+    ctx.accounts.pending_action.submitted_slot = Clock::get()?.slot;
+    ctx.accounts.pending_action.executed = false;
+
+    ctx.accounts.timelock_config.next_action_id = action_id
+        .checked_add(1)
+        .ok_or(TokenBridgeRelayerError::InsufficientFunds)?;
+
+    Ok(())
+}
+
+/// Applies a `PendingAdminAction` proposed by [`propose_set_token_swap_rate_precision`], once
+/// `TimelockConfig::delay_slots` has elapsed since it was submitted.
+#[derive(Accounts)]
+#[instruction(action_id: u64)]
+pub struct ExecuteSetTokenSwapRatePrecision<'info> {
+    pub owner: Signer<'info>,
+
+    #[account(seeds = [SEED_PREFIX_TIMELOCK_CONFIG], bump)]
+    pub timelock_config: Account<'info, TimelockConfig>,
+
+    #[account(
+        mut,
+        seeds = [SEED_PREFIX_PENDING_ACTION, &action_id.to_be_bytes()],
+        bump,
+    )]
+    pub pending_action: Account<'info, PendingAdminAction>,
+
+    /// CHECK: validated against the pending action's encoded mint below.
+    pub mint: AccountInfo<'info>,
+
+    #[account(
+        mut,
+        seeds = [SEED_PREFIX_REGISTERED_TOKEN, mint.key().as_ref()],
+        bump,
+    )]
+    pub registered_token: Account<'info, RegisteredToken>,
+}
+
+pub fn execute_set_token_swap_rate_precision(
+    ctx: Context<ExecuteSetTokenSwapRatePrecision>,
+    _action_id: u64,
+) -> Result<()> {
+    require!(
+        ctx.accounts.pending_action.action_type == ADMIN_ACTION_SET_TOKEN_SWAP_RATE_PRECISION,
+        TokenBridgeRelayerError::PendingActionTypeMismatch
+    );
+    require!(
+        !ctx.accounts.pending_action.executed,
+        TokenBridgeRelayerError::PendingActionAlreadyExecuted
+    );
+    require!(
+        Clock::get()?.slot
+            >= ctx
+                .accounts
+                .pending_action
+                .submitted_slot
+                .saturating_add(ctx.accounts.timelock_config.delay_slots),
+        TokenBridgeRelayerError::TimelockNotElapsed
+    );
+
+    let (mint, swap_rate_precision_override) =
+        <(Pubkey, u32)>::try_from_slice(&ctx.accounts.pending_action.encoded_args)?;
+    require_keys_eq!(
+        ctx.accounts.mint.key(),
+        mint,
+        TokenBridgeRelayerError::PendingActionTargetMismatch
+    );
+
+    apply_swap_rate_precision_override(
+        &mut ctx.accounts.registered_token,
+        swap_rate_precision_override,
+    );
+
+    ctx.accounts.pending_action.executed = true;
+
+    Ok(())
+}
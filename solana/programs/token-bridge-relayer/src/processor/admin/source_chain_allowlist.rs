@@ -0,0 +1,95 @@
+use crate::{
+    state::{AllowedSourceChain, RedeemerConfig, SourceChainAllowlist},
+    SEED_PREFIX_ALLOWED_CHAIN, SEED_PREFIX_REDEEMER, SEED_PREFIX_SOURCE_ALLOWLIST,
+};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct SetSourceAllowlistEnabled<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    #[account(
+        seeds = [SEED_PREFIX_REDEEMER],
+        bump = config.bump,
+        has_one = owner,
+    )]
+    pub config: Account<'info, RedeemerConfig>,
+
+    #[account(
+        init_if_needed,
+        payer = owner,
+        seeds = [SEED_PREFIX_SOURCE_ALLOWLIST],
+        bump,
+        space = SourceChainAllowlist::MAXIMUM_SIZE,
+    )]
+    pub source_chain_allowlist: Account<'info, SourceChainAllowlist>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn enable_source_allowlist(ctx: Context<SetSourceAllowlistEnabled>) -> Result<()> {
+    ctx.accounts.source_chain_allowlist.enabled = true;
+    Ok(())
+}
+
+pub fn disable_source_allowlist(ctx: Context<SetSourceAllowlistEnabled>) -> Result<()> {
+    ctx.accounts.source_chain_allowlist.enabled = false;
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(chain: u16)]
+pub struct AddSourceChain<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    #[account(
+        seeds = [SEED_PREFIX_REDEEMER],
+        bump = config.bump,
+        has_one = owner,
+    )]
+    pub config: Account<'info, RedeemerConfig>,
+
+    #[account(
+        init_if_needed,
+        payer = owner,
+        seeds = [SEED_PREFIX_ALLOWED_CHAIN, &chain.to_be_bytes()],
+        bump,
+        space = AllowedSourceChain::MAXIMUM_SIZE,
+    )]
+    pub allowed_source_chain: Account<'info, AllowedSourceChain>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn add_source_chain(ctx: Context<AddSourceChain>, chain: u16) -> Result<()> {
+    ctx.accounts.allowed_source_chain.chain = chain;
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(chain: u16)]
+pub struct RemoveSourceChain<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    #[account(
+        seeds = [SEED_PREFIX_REDEEMER],
+        bump = config.bump,
+        has_one = owner,
+    )]
+    pub config: Account<'info, RedeemerConfig>,
+
+    #[account(
+        mut,
+        close = owner,
+        seeds = [SEED_PREFIX_ALLOWED_CHAIN, &chain.to_be_bytes()],
+        bump,
+    )]
+    pub allowed_source_chain: Account<'info, AllowedSourceChain>,
+}
+
+pub fn remove_source_chain(_ctx: Context<RemoveSourceChain>, _chain: u16) -> Result<()> {
+    Ok(())
+}
@@ -0,0 +1,96 @@
+use crate::{
+    state::{SenderConfig, SupportedChain, SupportedChainsConfig},
+    SEED_PREFIX_SENDER, SEED_PREFIX_SUPPORTED_CHAIN, SEED_PREFIX_SUPPORTED_CHAINS_CONFIG,
+};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct SetEnforceChainAllowlist<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    #[account(
+        seeds = [SEED_PREFIX_SENDER],
+        bump = config.bump,
+        has_one = owner,
+    )]
+    pub config: Account<'info, SenderConfig>,
+
+    #[account(
+        init_if_needed,
+        payer = owner,
+        seeds = [SEED_PREFIX_SUPPORTED_CHAINS_CONFIG],
+        bump,
+        space = SupportedChainsConfig::MAXIMUM_SIZE,
+    )]
+    pub supported_chains_config: Account<'info, SupportedChainsConfig>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn set_enforce_chain_allowlist(
+    ctx: Context<SetEnforceChainAllowlist>,
+    enforce_allowlist: bool,
+) -> Result<()> {
+    ctx.accounts.supported_chains_config.enforce_allowlist = enforce_allowlist;
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(chain: u16)]
+pub struct RegisterSupportedChain<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    #[account(
+        seeds = [SEED_PREFIX_SENDER],
+        bump = config.bump,
+        has_one = owner,
+    )]
+    pub config: Account<'info, SenderConfig>,
+
+    #[account(
+        init_if_needed,
+        payer = owner,
+        seeds = [SEED_PREFIX_SUPPORTED_CHAIN, &chain.to_be_bytes()],
+        bump,
+        space = SupportedChain::MAXIMUM_SIZE,
+    )]
+    pub supported_chain: Account<'info, SupportedChain>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn register_supported_chain(ctx: Context<RegisterSupportedChain>, chain: u16) -> Result<()> {
+    ctx.accounts.supported_chain.chain = chain;
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(chain: u16)]
+pub struct DeregisterSupportedChain<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    #[account(
+        seeds = [SEED_PREFIX_SENDER],
+        bump = config.bump,
+        has_one = owner,
+    )]
+    pub config: Account<'info, SenderConfig>,
+
+    #[account(
+        mut,
+        close = owner,
+        seeds = [SEED_PREFIX_SUPPORTED_CHAIN, &chain.to_be_bytes()],
+        bump,
+    )]
+    pub supported_chain: Account<'info, SupportedChain>,
+}
+
+pub fn deregister_supported_chain(
+    _ctx: Context<DeregisterSupportedChain>,
+    _chain: u16,
+) -> Result<()> {
+    Ok(())
+}
@@ -0,0 +1,22 @@
+use crate::{events::AssistantChanged, state::OwnerConfig};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct UpdateAssistant<'info> {
+    pub owner: Signer<'info>,
+
+    #[account(mut, seeds = [b"owner_config"], bump, has_one = owner)]
+    pub owner_config: Account<'info, OwnerConfig>,
+}
+
+pub fn update_assistant(ctx: Context<UpdateAssistant>, new_assistant: Pubkey) -> Result<()> {
+    let old_assistant = ctx.accounts.owner_config.assistant;
+    ctx.accounts.owner_config.assistant = new_assistant;
+
+    emit!(AssistantChanged {
+        old_assistant,
+        new_assistant,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,37 @@
+use crate::{
+    error::TokenBridgeRelayerError,
+    state::{ForeignContract, SenderConfig},
+    SEED_PREFIX_FOREIGN_CONTRACT, SEED_PREFIX_SENDER,
+};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+#[instruction(chain: u16)]
+pub struct UpdateFeeCeiling<'info> {
+    pub owner: Signer<'info>,
+
+    #[account(
+        seeds = [SEED_PREFIX_SENDER],
+        bump = config.bump,
+        has_one = owner,
+    )]
+    pub config: Account<'info, SenderConfig>,
+
+    #[account(
+        mut,
+        seeds = [SEED_PREFIX_FOREIGN_CONTRACT, &chain.to_be_bytes()[..]],
+        bump,
+    )]
+    pub foreign_contract: Account<'info, ForeignContract>,
+}
+
+pub fn update_fee_ceiling(ctx: Context<UpdateFeeCeiling>, _chain: u16, max_fee: u64) -> Result<()> {
+    let foreign_contract = &mut ctx.accounts.foreign_contract;
+    require!(
+        max_fee == 0 || max_fee >= foreign_contract.min_fee,
+        TokenBridgeRelayerError::InvalidFeeBounds
+    );
+
+    foreign_contract.max_fee = max_fee;
+    Ok(())
+}
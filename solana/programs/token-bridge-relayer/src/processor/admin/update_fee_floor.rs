@@ -0,0 +1,37 @@
+use crate::{
+    error::TokenBridgeRelayerError,
+    state::{ForeignContract, SenderConfig},
+    SEED_PREFIX_FOREIGN_CONTRACT, SEED_PREFIX_SENDER,
+};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+#[instruction(chain: u16)]
+pub struct UpdateFeeFloor<'info> {
+    pub owner: Signer<'info>,
+
+    #[account(
+        seeds = [SEED_PREFIX_SENDER],
+        bump = config.bump,
+        has_one = owner,
+    )]
+    pub config: Account<'info, SenderConfig>,
+
+    #[account(
+        mut,
+        seeds = [SEED_PREFIX_FOREIGN_CONTRACT, &chain.to_be_bytes()[..]],
+        bump,
+    )]
+    pub foreign_contract: Account<'info, ForeignContract>,
+}
+
+pub fn update_fee_floor(ctx: Context<UpdateFeeFloor>, _chain: u16, min_fee: u64) -> Result<()> {
+    let foreign_contract = &mut ctx.accounts.foreign_contract;
+    require!(
+        foreign_contract.max_fee == 0 || min_fee <= foreign_contract.max_fee,
+        TokenBridgeRelayerError::InvalidFeeBounds
+    );
+
+    foreign_contract.min_fee = min_fee;
+    Ok(())
+}
@@ -0,0 +1,92 @@
+use crate::{
+    error::TokenBridgeRelayerError,
+    events::FeeNativeTokenAmountChanged,
+    state::{
+        AdminAuditLog, AuditLogConfig, ForeignContract, OwnerConfig,
+        AUDIT_ACTION_UPDATE_FEE_NATIVE_TOKEN_AMOUNT,
+    },
+    SEED_PREFIX_AUDIT_LOG, SEED_PREFIX_AUDIT_LOG_CONFIG, SEED_PREFIX_FOREIGN_CONTRACT,
+};
+use anchor_lang::prelude::*;
+
+/// Owner-or-assistant, matching [`crate::processor::update_relayer_fee`]. Unlike `fee`, this
+/// field has no `min_fee`/`max_fee` clamp of its own — a native-token-denominated fee floor is
+/// meant to track a real gas cost the owner sets directly, not a USD-scaled value that needs
+/// bounding against drift.
+#[derive(Accounts)]
+#[instruction(chain: u16)]
+pub struct UpdateFeeNativeToken<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    #[account(seeds = [b"owner_config"], bump)]
+    pub owner_config: Account<'info, OwnerConfig>,
+
+    #[account(
+        mut,
+        seeds = [SEED_PREFIX_FOREIGN_CONTRACT, &chain.to_be_bytes()[..]],
+        bump,
+    )]
+    pub foreign_contract: Account<'info, ForeignContract>,
+
+    #[account(
+        init_if_needed,
+        payer = owner,
+        seeds = [SEED_PREFIX_AUDIT_LOG_CONFIG],
+        bump,
+        space = AuditLogConfig::MAXIMUM_SIZE,
+    )]
+    pub audit_log_config: Account<'info, AuditLogConfig>,
+
+    #[account(
+        init,
+        payer = owner,
+        seeds = [SEED_PREFIX_AUDIT_LOG, &audit_log_config.counter.to_be_bytes()],
+        bump,
+        space = AdminAuditLog::MAXIMUM_SIZE,
+    )]
+    pub audit_log_entry: Account<'info, AdminAuditLog>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Sets [`ForeignContract::fee_native_token_amount`], the raw-token-unit fee floor
+/// `prepare_transfer` takes the max of against whatever `fee`/`fee_in_token_units` resolves to.
+/// `0` unsets the floor, falling back to `fee` alone.
+pub fn update_fee_native_token(
+    ctx: Context<UpdateFeeNativeToken>,
+    chain: u16,
+    fee_native_token_amount: u64,
+) -> Result<()> {
+    require!(
+        ctx.accounts
+            .owner_config
+            .is_authorized(&ctx.accounts.owner.key()),
+        TokenBridgeRelayerError::OwnerOrAssistantOnly
+    );
+
+    let old_fee_native_token_amount = ctx.accounts.foreign_contract.fee_native_token_amount;
+    ctx.accounts.foreign_contract.fee_native_token_amount = fee_native_token_amount;
+
+    ctx.accounts.audit_log_entry.record(
+        AUDIT_ACTION_UPDATE_FEE_NATIVE_TOKEN_AMOUNT,
+        ctx.accounts.owner.key(),
+        None,
+        old_fee_native_token_amount,
+        fee_native_token_amount,
+    )?;
+    ctx.accounts.audit_log_config.counter = ctx
+        .accounts
+        .audit_log_config
+        .counter
+        .checked_add(1)
+        .ok_or(TokenBridgeRelayerError::InsufficientFunds)?;
+
+    emit!(FeeNativeTokenAmountChanged {
+        chain,
+        old_fee_native_token_amount,
+        new_fee_native_token_amount: fee_native_token_amount,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,193 @@
+use crate::{
+    error::TokenBridgeRelayerError,
+    events::FeeRecipientChanged,
+    state::{
+        AdminAuditLog, AuditLogConfig, PendingAdminAction, RedeemerConfig, TimelockConfig,
+        ADMIN_ACTION_UPDATE_FEE_RECIPIENT, AUDIT_ACTION_UPDATE_FEE_RECIPIENT,
+    },
+    SEED_PREFIX_AUDIT_LOG, SEED_PREFIX_AUDIT_LOG_CONFIG, SEED_PREFIX_PENDING_ACTION,
+    SEED_PREFIX_REDEEMER, SEED_PREFIX_TIMELOCK_CONFIG,
+};
+use anchor_lang::prelude::*;
+
+/// Upper bound on `PendingAdminAction::encoded_args` for this instruction: a `Pubkey` plus an
+/// `Option<Pubkey>` serialized in its worst case (`Some`).
+const FEE_RECIPIENT_ARGS_SIZE: usize = 32 + (1 + 32);
+
+fn apply_fee_recipient_update(
+    config: &mut Account<RedeemerConfig>,
+    audit_log_config: &mut Account<AuditLogConfig>,
+    audit_log_entry: &mut Account<AdminAuditLog>,
+    actor: Pubkey,
+    new_fee_recipient: Pubkey,
+    new_secondary_fee_recipient: Option<Pubkey>,
+) -> Result<()> {
+    let old_fee_recipient = config.fee_recipient;
+    config.fee_recipient = new_fee_recipient;
+    config.secondary_fee_recipient = new_secondary_fee_recipient;
+
+    // `old_value`/`new_value` are not meaningful here since the change is a `Pubkey`, not a
+    // `u64`; the recipient addresses themselves are captured in `target` and the emitted event.
+    audit_log_entry.record(
+        AUDIT_ACTION_UPDATE_FEE_RECIPIENT,
+        actor,
+        Some(new_fee_recipient),
+        0,
+        0,
+    )?;
+    audit_log_config.counter = audit_log_config
+        .counter
+        .checked_add(1)
+        .ok_or(TokenBridgeRelayerError::InsufficientFunds)?;
+
+    emit!(FeeRecipientChanged {
+        old_fee_recipient,
+        new_fee_recipient,
+    });
+
+    Ok(())
+}
+
+/// Changing where relayer fees get paid out is sensitive enough that it only goes through the
+/// timelocked propose/execute flow below — there is no instant variant, so a compromised owner
+/// key can't redirect fees without giving `TimelockConfig::delay_slots` for the change to be
+/// noticed and reacted to.
+#[derive(Accounts)]
+pub struct ProposeUpdateFeeRecipient<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    #[account(
+        seeds = [SEED_PREFIX_REDEEMER],
+        bump = config.bump,
+        has_one = owner,
+    )]
+    pub config: Account<'info, RedeemerConfig>,
+
+    #[account(
+        init_if_needed,
+        payer = owner,
+        seeds = [SEED_PREFIX_TIMELOCK_CONFIG],
+        bump,
+        space = TimelockConfig::MAXIMUM_SIZE,
+    )]
+    pub timelock_config: Account<'info, TimelockConfig>,
+
+    #[account(
+        init,
+        payer = owner,
+        seeds = [SEED_PREFIX_PENDING_ACTION, &timelock_config.next_action_id.to_be_bytes()],
+        bump,
+        space = PendingAdminAction::space_for(FEE_RECIPIENT_ARGS_SIZE),
+    )]
+    pub pending_action: Account<'info, PendingAdminAction>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn propose_update_fee_recipient(
+    ctx: Context<ProposeUpdateFeeRecipient>,
+    new_fee_recipient: Pubkey,
+    new_secondary_fee_recipient: Option<Pubkey>,
+) -> Result<()> {
+    let action_id = ctx.accounts.timelock_config.next_action_id;
+
+    ctx.accounts.pending_action.action_id = action_id;
+    ctx.accounts.pending_action.action_type = ADMIN_ACTION_UPDATE_FEE_RECIPIENT;
+    ctx.accounts.pending_action.encoded_args =
+        (new_fee_recipient, new_secondary_fee_recipient).try_to_vec()?;
+    ctx.accounts.pending_action.submitted_slot = Clock::get()?.slot;
+    ctx.accounts.pending_action.executed = false;
+
+    ctx.accounts.timelock_config.next_action_id = action_id
+        .checked_add(1)
+        .ok_or(TokenBridgeRelayerError::InsufficientFunds)?;
+
+    Ok(())
+}
+
+/// Applies a `PendingAdminAction` proposed by [`propose_update_fee_recipient`], once
+/// `TimelockConfig::delay_slots` has elapsed since it was submitted.
+#[derive(Accounts)]
+#[instruction(action_id: u64)]
+pub struct ExecuteUpdateFeeRecipient<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [SEED_PREFIX_REDEEMER],
+        bump = config.bump,
+        has_one = owner,
+    )]
+    pub config: Account<'info, RedeemerConfig>,
+
+    #[account(seeds = [SEED_PREFIX_TIMELOCK_CONFIG], bump)]
+    pub timelock_config: Account<'info, TimelockConfig>,
+
+    #[account(
+        mut,
+        seeds = [SEED_PREFIX_PENDING_ACTION, &action_id.to_be_bytes()],
+        bump,
+    )]
+    pub pending_action: Account<'info, PendingAdminAction>,
+
+    #[account(
+        init_if_needed,
+        payer = owner,
+        seeds = [SEED_PREFIX_AUDIT_LOG_CONFIG],
+        bump,
+        space = AuditLogConfig::MAXIMUM_SIZE,
+    )]
+    pub audit_log_config: Account<'info, AuditLogConfig>,
+
+    #[account(
+        init,
+        payer = owner,
+        seeds = [SEED_PREFIX_AUDIT_LOG, &audit_log_config.counter.to_be_bytes()],
+        bump,
+        space = AdminAuditLog::MAXIMUM_SIZE,
+    )]
+    pub audit_log_entry: Account<'info, AdminAuditLog>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn execute_update_fee_recipient(
+    ctx: Context<ExecuteUpdateFeeRecipient>,
+    _action_id: u64,
+) -> Result<()> {
+    require!(
+        ctx.accounts.pending_action.action_type == ADMIN_ACTION_UPDATE_FEE_RECIPIENT,
+        TokenBridgeRelayerError::PendingActionTypeMismatch
+    );
+    require!(
+        !ctx.accounts.pending_action.executed,
+        TokenBridgeRelayerError::PendingActionAlreadyExecuted
+    );
+    require!(
+        Clock::get()?.slot
+            >= ctx
+                .accounts
+                .pending_action
+                .submitted_slot
+                .saturating_add(ctx.accounts.timelock_config.delay_slots),
+        TokenBridgeRelayerError::TimelockNotElapsed
+    );
+
+    let (new_fee_recipient, new_secondary_fee_recipient) =
+        <(Pubkey, Option<Pubkey>)>::try_from_slice(&ctx.accounts.pending_action.encoded_args)?;
+
+    apply_fee_recipient_update(
+        &mut ctx.accounts.config,
+        &mut ctx.accounts.audit_log_config,
+        &mut ctx.accounts.audit_log_entry,
+        ctx.accounts.owner.key(),
+        new_fee_recipient,
+        new_secondary_fee_recipient,
+    )?;
+
+    ctx.accounts.pending_action.executed = true;
+
+    Ok(())
+}
@@ -0,0 +1,38 @@
+use crate::{
+    error::TokenBridgeRelayerError, events::FeeSplitChanged, state::RedeemerConfig,
+    SEED_PREFIX_REDEEMER,
+};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct UpdateFeeSplit<'info> {
+    pub owner: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [SEED_PREFIX_REDEEMER],
+        bump = config.bump,
+        has_one = owner,
+    )]
+    pub config: Account<'info, RedeemerConfig>,
+}
+
+/// Sets the share of the relayer fee, in basis points out of 10,000, that goes to
+/// `fee_recipient`; the remainder goes to `secondary_fee_recipient`. Has no effect on fee
+/// distribution while `secondary_fee_recipient` is unset.
+pub fn update_fee_split(ctx: Context<UpdateFeeSplit>, new_split_bps: u16) -> Result<()> {
+    require!(
+        new_split_bps <= 10_000,
+        TokenBridgeRelayerError::InvalidFeeSplit
+    );
+
+    let old_split_bps = ctx.accounts.config.fee_split_bps;
+    ctx.accounts.config.fee_split_bps = new_split_bps;
+
+    emit!(FeeSplitChanged {
+        old_split_bps,
+        new_split_bps,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,51 @@
+use crate::{
+    error::TokenBridgeRelayerError, events::ForeignEndpointUpdated, state::ForeignContract,
+    state::SenderConfig, SEED_PREFIX_FOREIGN_CONTRACT, SEED_PREFIX_SENDER,
+};
+use anchor_lang::prelude::*;
+
+/// Updates only `foreign_contract.token_bridge_foreign_endpoint`, leaving `chain`, `address`,
+/// and the fee bounds untouched. Needed because Token Bridge occasionally re-registers its
+/// endpoint for a chain it already supports, and re-running `register_foreign_contract` would
+/// also reset the relayer fee back to whatever the caller passes in.
+#[derive(Accounts)]
+#[instruction(chain: u16)]
+pub struct UpdateForeignEndpoint<'info> {
+    pub owner: Signer<'info>,
+
+    #[account(
+        seeds = [SEED_PREFIX_SENDER],
+        bump = config.bump,
+        has_one = owner,
+    )]
+    pub config: Account<'info, SenderConfig>,
+
+    #[account(
+        mut,
+        seeds = [SEED_PREFIX_FOREIGN_CONTRACT, &chain.to_be_bytes()[..]],
+        bump,
+    )]
+    pub foreign_contract: Account<'info, ForeignContract>,
+}
+
+pub fn update_foreign_endpoint(
+    ctx: Context<UpdateForeignEndpoint>,
+    chain: u16,
+    token_bridge_foreign_endpoint: [u8; 32],
+) -> Result<()> {
+    require!(
+        ForeignContract::is_valid_address(&token_bridge_foreign_endpoint),
+        TokenBridgeRelayerError::InvalidPublicKey
+    );
+
+    let old_endpoint = ctx.accounts.foreign_contract.token_bridge_foreign_endpoint;
+    ctx.accounts.foreign_contract.token_bridge_foreign_endpoint = token_bridge_foreign_endpoint;
+
+    emit!(ForeignEndpointUpdated {
+        chain,
+        old_endpoint,
+        new_endpoint: token_bridge_foreign_endpoint,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,23 @@
+use crate::{state::SenderConfig, SEED_PREFIX_SENDER};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct UpdateLargeTransferThreshold<'info> {
+    pub owner: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [SEED_PREFIX_SENDER],
+        bump = config.bump,
+        has_one = owner,
+    )]
+    pub config: Account<'info, SenderConfig>,
+}
+
+pub fn update_large_transfer_threshold(
+    ctx: Context<UpdateLargeTransferThreshold>,
+    large_transfer_threshold: u64,
+) -> Result<()> {
+    ctx.accounts.config.large_transfer_threshold = large_transfer_threshold;
+    Ok(())
+}
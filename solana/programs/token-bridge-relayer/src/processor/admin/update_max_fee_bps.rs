@@ -0,0 +1,32 @@
+use crate::{
+    state::{RegisteredToken, SenderConfig},
+    SEED_PREFIX_REGISTERED_TOKEN, SEED_PREFIX_SENDER,
+};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct UpdateMaxFeeBps<'info> {
+    pub owner: Signer<'info>,
+
+    #[account(
+        seeds = [SEED_PREFIX_SENDER],
+        bump = config.bump,
+        has_one = owner,
+    )]
+    pub config: Account<'info, SenderConfig>,
+
+    /// CHECK: the mint whose fee cap is being updated; only used to derive the PDA.
+    pub mint: AccountInfo<'info>,
+
+    #[account(
+        mut,
+        seeds = [SEED_PREFIX_REGISTERED_TOKEN, mint.key().as_ref()],
+        bump,
+    )]
+    pub registered_token: Account<'info, RegisteredToken>,
+}
+
+pub fn update_max_fee_bps(ctx: Context<UpdateMaxFeeBps>, max_fee_bps: u16) -> Result<()> {
+    ctx.accounts.registered_token.max_fee_bps = max_fee_bps;
+    Ok(())
+}
@@ -0,0 +1,25 @@
+use crate::{events::MaxForeignContractsChanged, state::OwnerConfig};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct UpdateMaxForeignContracts<'info> {
+    pub owner: Signer<'info>,
+
+    #[account(mut, seeds = [b"owner_config"], bump, has_one = owner)]
+    pub owner_config: Account<'info, OwnerConfig>,
+}
+
+pub fn update_max_foreign_contracts(
+    ctx: Context<UpdateMaxForeignContracts>,
+    max_foreign_contracts: u16,
+) -> Result<()> {
+    let old_max_foreign_contracts = ctx.accounts.owner_config.max_foreign_contracts;
+    ctx.accounts.owner_config.max_foreign_contracts = max_foreign_contracts;
+
+    emit!(MaxForeignContractsChanged {
+        old_max_foreign_contracts,
+        new_max_foreign_contracts: max_foreign_contracts,
+    });
+
+    Ok(())
+}
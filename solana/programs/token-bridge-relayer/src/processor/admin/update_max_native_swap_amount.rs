@@ -0,0 +1,79 @@
+use crate::{
+    error::TokenBridgeRelayerError,
+    state::{
+        AdminAuditLog, AuditLogConfig, OwnerConfig, RegisteredToken,
+        AUDIT_ACTION_UPDATE_MAX_NATIVE_SWAP_AMOUNT,
+    },
+    SEED_PREFIX_AUDIT_LOG, SEED_PREFIX_AUDIT_LOG_CONFIG, SEED_PREFIX_REGISTERED_TOKEN,
+};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct UpdateMaxNativeSwapAmount<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    #[account(seeds = [b"owner_config"], bump)]
+    pub owner_config: Account<'info, OwnerConfig>,
+
+    /// CHECK: the mint being updated; only used to derive the PDA.
+    pub mint: AccountInfo<'info>,
+
+    #[account(
+        mut,
+        seeds = [SEED_PREFIX_REGISTERED_TOKEN, mint.key().as_ref()],
+        bump,
+    )]
+    pub registered_token: Account<'info, RegisteredToken>,
+
+    #[account(
+        init_if_needed,
+        payer = owner,
+        seeds = [SEED_PREFIX_AUDIT_LOG_CONFIG],
+        bump,
+        space = AuditLogConfig::MAXIMUM_SIZE,
+    )]
+    pub audit_log_config: Account<'info, AuditLogConfig>,
+
+    #[account(
+        init,
+        payer = owner,
+        seeds = [SEED_PREFIX_AUDIT_LOG, &audit_log_config.counter.to_be_bytes()],
+        bump,
+        space = AdminAuditLog::MAXIMUM_SIZE,
+    )]
+    pub audit_log_entry: Account<'info, AdminAuditLog>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn update_max_native_swap_amount(
+    ctx: Context<UpdateMaxNativeSwapAmount>,
+    max_native_swap_amount: u64,
+) -> Result<()> {
+    require!(
+        ctx.accounts
+            .owner_config
+            .is_authorized(&ctx.accounts.owner.key()),
+        TokenBridgeRelayerError::OwnerOrAssistantOnly
+    );
+
+    let old_max_native_swap_amount = ctx.accounts.registered_token.max_native_swap_amount;
+    ctx.accounts.registered_token.max_native_swap_amount = max_native_swap_amount;
+
+    ctx.accounts.audit_log_entry.record(
+        AUDIT_ACTION_UPDATE_MAX_NATIVE_SWAP_AMOUNT,
+        ctx.accounts.owner.key(),
+        Some(ctx.accounts.mint.key()),
+        old_max_native_swap_amount,
+        max_native_swap_amount,
+    )?;
+    ctx.accounts.audit_log_config.counter = ctx
+        .accounts
+        .audit_log_config
+        .counter
+        .checked_add(1)
+        .ok_or(TokenBridgeRelayerError::InsufficientFunds)?;
+
+    Ok(())
+}
@@ -0,0 +1,45 @@
+use crate::{
+    events::MaxNativeSwapPerTxChanged,
+    state::{OwnerConfig, RegisteredToken},
+    SEED_PREFIX_REGISTERED_TOKEN,
+};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct UpdateMaxNativeSwapPerTx<'info> {
+    pub owner: Signer<'info>,
+
+    #[account(seeds = [b"owner_config"], bump, has_one = owner)]
+    pub owner_config: Account<'info, OwnerConfig>,
+
+    /// CHECK: the mint being updated; only used to derive the PDA.
+    pub mint: AccountInfo<'info>,
+
+    #[account(
+        mut,
+        seeds = [SEED_PREFIX_REGISTERED_TOKEN, mint.key().as_ref()],
+        bump,
+    )]
+    pub registered_token: Account<'info, RegisteredToken>,
+}
+
+/// Sets `max_native_swap_per_tx`, further capping a single transfer's native swap below
+/// `max_native_swap_amount` to limit per-transaction risk independently of the program-wide max.
+/// `0` reverts to only `max_native_swap_amount` applying. Owner-only, unlike
+/// `update_max_native_swap_amount`: a wrong per-transaction cap is a risk-limiting control, not a
+/// day-to-day parameter, so it isn't delegated to the assistant key.
+pub fn update_max_native_swap_per_tx(
+    ctx: Context<UpdateMaxNativeSwapPerTx>,
+    max_native_swap_per_tx: u64,
+) -> Result<()> {
+    let old_max_native_swap_per_tx = ctx.accounts.registered_token.max_native_swap_per_tx;
+    ctx.accounts.registered_token.max_native_swap_per_tx = max_native_swap_per_tx;
+
+    emit!(MaxNativeSwapPerTxChanged {
+        mint: ctx.accounts.mint.key(),
+        old_max_native_swap_per_tx,
+        new_max_native_swap_per_tx: max_native_swap_per_tx,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,25 @@
+use crate::{events::MaxRegisteredTokensChanged, state::OwnerConfig};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct UpdateMaxRegisteredTokens<'info> {
+    pub owner: Signer<'info>,
+
+    #[account(mut, seeds = [b"owner_config"], bump, has_one = owner)]
+    pub owner_config: Account<'info, OwnerConfig>,
+}
+
+pub fn update_max_registered_tokens(
+    ctx: Context<UpdateMaxRegisteredTokens>,
+    max_registered_tokens: u16,
+) -> Result<()> {
+    let old_max_registered_tokens = ctx.accounts.owner_config.max_registered_tokens;
+    ctx.accounts.owner_config.max_registered_tokens = max_registered_tokens;
+
+    emit!(MaxRegisteredTokensChanged {
+        old_max_registered_tokens,
+        new_max_registered_tokens: max_registered_tokens,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,23 @@
+use crate::{state::SenderConfig, SEED_PREFIX_SENDER};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct UpdateMaxSwapRateAge<'info> {
+    pub owner: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [SEED_PREFIX_SENDER],
+        bump = config.bump,
+        has_one = owner,
+    )]
+    pub config: Account<'info, SenderConfig>,
+}
+
+pub fn update_max_swap_rate_age(
+    ctx: Context<UpdateMaxSwapRateAge>,
+    max_swap_rate_age_slots: u64,
+) -> Result<()> {
+    ctx.accounts.config.max_swap_rate_age_slots = max_swap_rate_age_slots;
+    Ok(())
+}
@@ -0,0 +1,39 @@
+use crate::{
+    error::TokenBridgeRelayerError,
+    state::{OwnerConfig, RegisteredToken},
+    SEED_PREFIX_REGISTERED_TOKEN,
+};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct UpdateMaxTransferAmount<'info> {
+    pub owner: Signer<'info>,
+
+    #[account(seeds = [b"owner_config"], bump)]
+    pub owner_config: Account<'info, OwnerConfig>,
+
+    /// CHECK: the mint being updated; only used to derive the PDA.
+    pub mint: AccountInfo<'info>,
+
+    #[account(
+        mut,
+        seeds = [SEED_PREFIX_REGISTERED_TOKEN, mint.key().as_ref()],
+        bump,
+    )]
+    pub registered_token: Account<'info, RegisteredToken>,
+}
+
+pub fn update_max_transfer_amount(
+    ctx: Context<UpdateMaxTransferAmount>,
+    max_transfer_amount: u64,
+) -> Result<()> {
+    require!(
+        ctx.accounts
+            .owner_config
+            .is_authorized(&ctx.accounts.owner.key()),
+        TokenBridgeRelayerError::OwnerOrAssistantOnly
+    );
+
+    ctx.accounts.registered_token.max_transfer_amount = max_transfer_amount;
+    Ok(())
+}
@@ -0,0 +1,216 @@
+use crate::{
+    error::TokenBridgeRelayerError,
+    events::{RelayerFeeChanged, RelayerFeePrecisionUpdated},
+    state::{
+        ForeignContract, PendingAdminAction, RedeemerConfig, SenderConfig, TimelockConfig,
+        ADMIN_ACTION_UPDATE_PRECISION_AND_FEES,
+    },
+    SEED_PREFIX_FOREIGN_CONTRACT, SEED_PREFIX_PENDING_ACTION, SEED_PREFIX_REDEEMER,
+    SEED_PREFIX_SENDER, SEED_PREFIX_TIMELOCK_CONFIG,
+};
+use anchor_lang::prelude::*;
+
+/// Size, in bytes, of one `(u16, u64)` entry once Borsh-serialized inside
+/// `PendingAdminAction::encoded_args`.
+const PRECISION_AND_FEES_ENTRY_SIZE: usize = 2 + 8;
+
+fn apply_precision_and_fees_update<'info>(
+    config: &mut Account<'info, SenderConfig>,
+    redeemer_config: &mut Account<'info, RedeemerConfig>,
+    remaining_accounts: &[AccountInfo<'info>],
+    program_id: &Pubkey,
+    relayer_fee_precision: u32,
+    fees: Vec<(u16, u64)>,
+) -> Result<()> {
+    require!(
+        relayer_fee_precision > 0,
+        TokenBridgeRelayerError::RelayerFeePrecisionCannotBeZero
+    );
+    require!(
+        remaining_accounts.len() == fees.len(),
+        TokenBridgeRelayerError::BatchLengthMismatch
+    );
+
+    let old_relayer_fee_precision = config.relayer_fee_precision;
+    config.relayer_fee_precision = relayer_fee_precision;
+    redeemer_config.relayer_fee_precision = relayer_fee_precision;
+
+    for ((chain, fee), foreign_contract_info) in fees.iter().zip(remaining_accounts.iter()) {
+        let mut foreign_contract = Account::<ForeignContract>::try_from(foreign_contract_info)
+            .map_err(|_| TokenBridgeRelayerError::InvalidForeignContractAccount)?;
+
+        let (expected_pda, _) = Pubkey::find_program_address(
+            &[SEED_PREFIX_FOREIGN_CONTRACT, &chain.to_be_bytes()[..]],
+            program_id,
+        );
+        require!(
+            expected_pda == foreign_contract_info.key(),
+            TokenBridgeRelayerError::InvalidForeignContractAccount
+        );
+        require_eq!(
+            foreign_contract.chain,
+            *chain,
+            TokenBridgeRelayerError::InvalidForeignContractAccount
+        );
+
+        require!(
+            foreign_contract.min_fee == 0 || *fee >= foreign_contract.min_fee,
+            TokenBridgeRelayerError::FeeOutOfBounds
+        );
+        require!(
+            foreign_contract.max_fee == 0 || *fee <= foreign_contract.max_fee,
+            TokenBridgeRelayerError::FeeOutOfBounds
+        );
+
+        let old_fee = foreign_contract.fee;
+        foreign_contract.fee = *fee;
+        foreign_contract.exit(program_id)?;
+
+        emit!(RelayerFeeChanged {
+            chain: *chain,
+            old_fee,
+            new_fee: *fee,
+        });
+    }
+
+    emit!(RelayerFeePrecisionUpdated {
+        old_relayer_fee_precision,
+        new_relayer_fee_precision: relayer_fee_precision,
+    });
+
+    Ok(())
+}
+
+/// Rescaling `relayer_fee_precision` touches every chain's fee at once, so — like
+/// [`super::update_fee_recipient`] — it only goes through the timelocked propose/execute flow
+/// below rather than applying instantly.
+#[derive(Accounts)]
+#[instruction(relayer_fee_precision: u32, fees: Vec<(u16, u64)>)]
+pub struct ProposeUpdatePrecisionAndFees<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    #[account(
+        seeds = [SEED_PREFIX_SENDER],
+        bump = config.bump,
+        has_one = owner,
+    )]
+    pub config: Account<'info, SenderConfig>,
+
+    #[account(
+        init_if_needed,
+        payer = owner,
+        seeds = [SEED_PREFIX_TIMELOCK_CONFIG],
+        bump,
+        space = TimelockConfig::MAXIMUM_SIZE,
+    )]
+    pub timelock_config: Account<'info, TimelockConfig>,
+
+    #[account(
+        init,
+        payer = owner,
+        seeds = [SEED_PREFIX_PENDING_ACTION, &timelock_config.next_action_id.to_be_bytes()],
+        bump,
+        space = PendingAdminAction::space_for(
+            4 + 4 + fees.len() * PRECISION_AND_FEES_ENTRY_SIZE
+        ),
+    )]
+    pub pending_action: Account<'info, PendingAdminAction>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn propose_update_precision_and_fees(
+    ctx: Context<ProposeUpdatePrecisionAndFees>,
+    relayer_fee_precision: u32,
+    fees: Vec<(u16, u64)>,
+) -> Result<()> {
+    let action_id = ctx.accounts.timelock_config.next_action_id;
+
+    ctx.accounts.pending_action.action_id = action_id;
+    ctx.accounts.pending_action.action_type = ADMIN_ACTION_UPDATE_PRECISION_AND_FEES;
+    ctx.accounts.pending_action.encoded_args = (relayer_fee_precision, fees).try_to_vec()?;
+    ctx.accounts.pending_action.submitted_slot = Clock::get()?.slot;
+    ctx.accounts.pending_action.executed = false;
+
+    ctx.accounts.timelock_config.next_action_id = action_id
+        .checked_add(1)
+        .ok_or(TokenBridgeRelayerError::InsufficientFunds)?;
+
+    Ok(())
+}
+
+/// Applies a `PendingAdminAction` proposed by [`propose_update_precision_and_fees`], once
+/// `TimelockConfig::delay_slots` has elapsed since it was submitted. `ctx.remaining_accounts`
+/// must line up one-to-one with the `fees` that were proposed, each entry naming the chain being
+/// rescaled and its `fee` under the new precision.
+#[derive(Accounts)]
+#[instruction(action_id: u64)]
+pub struct ExecuteUpdatePrecisionAndFees<'info> {
+    pub owner: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [SEED_PREFIX_SENDER],
+        bump = config.bump,
+        has_one = owner,
+    )]
+    pub config: Account<'info, SenderConfig>,
+
+    #[account(
+        mut,
+        seeds = [SEED_PREFIX_REDEEMER],
+        bump = redeemer_config.bump,
+        has_one = owner,
+    )]
+    pub redeemer_config: Account<'info, RedeemerConfig>,
+
+    #[account(seeds = [SEED_PREFIX_TIMELOCK_CONFIG], bump)]
+    pub timelock_config: Account<'info, TimelockConfig>,
+
+    #[account(
+        mut,
+        seeds = [SEED_PREFIX_PENDING_ACTION, &action_id.to_be_bytes()],
+        bump,
+    )]
+    pub pending_action: Account<'info, PendingAdminAction>,
+}
+
+pub fn execute_update_precision_and_fees<'info>(
+    ctx: Context<'_, '_, '_, 'info, ExecuteUpdatePrecisionAndFees<'info>>,
+    _action_id: u64,
+) -> Result<()> {
+    require!(
+        ctx.accounts.pending_action.action_type == ADMIN_ACTION_UPDATE_PRECISION_AND_FEES,
+        TokenBridgeRelayerError::PendingActionTypeMismatch
+    );
+    require!(
+        !ctx.accounts.pending_action.executed,
+        TokenBridgeRelayerError::PendingActionAlreadyExecuted
+    );
+    require!(
+        Clock::get()?.slot
+            >= ctx
+                .accounts
+                .pending_action
+                .submitted_slot
+                .saturating_add(ctx.accounts.timelock_config.delay_slots),
+        TokenBridgeRelayerError::TimelockNotElapsed
+    );
+
+    let (relayer_fee_precision, fees) =
+        <(u32, Vec<(u16, u64)>)>::try_from_slice(&ctx.accounts.pending_action.encoded_args)?;
+
+    apply_precision_and_fees_update(
+        &mut ctx.accounts.config,
+        &mut ctx.accounts.redeemer_config,
+        ctx.remaining_accounts,
+        ctx.program_id,
+        relayer_fee_precision,
+        fees,
+    )?;
+
+    ctx.accounts.pending_action.executed = true;
+
+    Ok(())
+}
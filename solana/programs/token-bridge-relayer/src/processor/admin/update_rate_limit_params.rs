@@ -0,0 +1,25 @@
+use crate::{state::SenderConfig, SEED_PREFIX_SENDER};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct UpdateRateLimitParams<'info> {
+    pub owner: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [SEED_PREFIX_SENDER],
+        bump = config.bump,
+        has_one = owner,
+    )]
+    pub config: Account<'info, SenderConfig>,
+}
+
+pub fn update_rate_limit_params(
+    ctx: Context<UpdateRateLimitParams>,
+    rate_limit_window_slots: u64,
+    rate_limit_max_amount: u64,
+) -> Result<()> {
+    ctx.accounts.config.rate_limit_window_slots = rate_limit_window_slots;
+    ctx.accounts.config.rate_limit_max_amount = rate_limit_max_amount;
+    Ok(())
+}
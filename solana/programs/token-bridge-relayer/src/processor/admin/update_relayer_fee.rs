@@ -0,0 +1,99 @@
+use crate::{
+    error::TokenBridgeRelayerError,
+    events::RelayerFeeChanged,
+    state::{
+        AdminAuditLog, AuditLogConfig, ForeignContract, OwnerConfig,
+        AUDIT_ACTION_UPDATE_RELAYER_FEE,
+    },
+    SEED_PREFIX_AUDIT_LOG, SEED_PREFIX_AUDIT_LOG_CONFIG, SEED_PREFIX_FOREIGN_CONTRACT,
+};
+use anchor_lang::prelude::*;
+
+/// Owner-or-assistant, matching [`crate::processor::update_relayer_fees_batch`]. The floor and
+/// ceiling `foreign_contract.fee` is clamped to are only settable by the owner, via
+/// `update_fee_floor` / `update_fee_ceiling`, so an assistant can move the fee within an
+/// owner-approved range but can't disable relaying by dropping it to zero.
+#[derive(Accounts)]
+#[instruction(chain: u16)]
+pub struct UpdateRelayerFee<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    #[account(seeds = [b"owner_config"], bump)]
+    pub owner_config: Account<'info, OwnerConfig>,
+
+    #[account(
+        mut,
+        seeds = [SEED_PREFIX_FOREIGN_CONTRACT, &chain.to_be_bytes()[..]],
+        bump,
+    )]
+    pub foreign_contract: Account<'info, ForeignContract>,
+
+    #[account(
+        init_if_needed,
+        payer = owner,
+        seeds = [SEED_PREFIX_AUDIT_LOG_CONFIG],
+        bump,
+        space = AuditLogConfig::MAXIMUM_SIZE,
+    )]
+    pub audit_log_config: Account<'info, AuditLogConfig>,
+
+    #[account(
+        init,
+        payer = owner,
+        seeds = [SEED_PREFIX_AUDIT_LOG, &audit_log_config.counter.to_be_bytes()],
+        bump,
+        space = AdminAuditLog::MAXIMUM_SIZE,
+    )]
+    pub audit_log_entry: Account<'info, AdminAuditLog>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn update_relayer_fee(
+    ctx: Context<UpdateRelayerFee>,
+    chain: u16,
+    relayer_fee: u64,
+) -> Result<()> {
+    require!(
+        ctx.accounts
+            .owner_config
+            .is_authorized(&ctx.accounts.owner.key()),
+        TokenBridgeRelayerError::OwnerOrAssistantOnly
+    );
+
+    let foreign_contract = &ctx.accounts.foreign_contract;
+    require!(
+        foreign_contract.min_fee == 0 || relayer_fee >= foreign_contract.min_fee,
+        TokenBridgeRelayerError::FeeOutOfBounds
+    );
+    require!(
+        foreign_contract.max_fee == 0 || relayer_fee <= foreign_contract.max_fee,
+        TokenBridgeRelayerError::FeeOutOfBounds
+    );
+
+    let old_fee = foreign_contract.fee;
+    ctx.accounts.foreign_contract.fee = relayer_fee;
+
+    ctx.accounts.audit_log_entry.record(
+        AUDIT_ACTION_UPDATE_RELAYER_FEE,
+        ctx.accounts.owner.key(),
+        None,
+        old_fee,
+        relayer_fee,
+    )?;
+    ctx.accounts.audit_log_config.counter = ctx
+        .accounts
+        .audit_log_config
+        .counter
+        .checked_add(1)
+        .ok_or(TokenBridgeRelayerError::InsufficientFunds)?;
+
+    emit!(RelayerFeeChanged {
+        chain,
+        old_fee,
+        new_fee: relayer_fee,
+    });
+
+    Ok(())
+}
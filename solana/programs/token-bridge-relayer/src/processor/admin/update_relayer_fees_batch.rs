@@ -0,0 +1,79 @@
+use crate::{
+    error::TokenBridgeRelayerError, events::RelayerFeeChanged, state::ForeignContract,
+    state::OwnerConfig, SEED_PREFIX_FOREIGN_CONTRACT,
+};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct UpdateRelayerFeesBatch<'info> {
+    pub owner: Signer<'info>,
+
+    #[account(seeds = [b"owner_config"], bump)]
+    pub owner_config: Account<'info, OwnerConfig>,
+}
+
+/// Updates the relayer fee for many chains in a single transaction. `fees` must line up
+/// one-to-one with `ctx.remaining_accounts`, which is expected to alternate a chain's
+/// `ForeignContract` account with an unused placeholder account so the accounts list keeps a
+/// fixed stride per chain (`[foreign_contract_0, placeholder_0, foreign_contract_1, ...]`).
+pub fn update_relayer_fees_batch(
+    ctx: Context<UpdateRelayerFeesBatch>,
+    fees: Vec<u64>,
+) -> Result<()> {
+    require!(
+        ctx.accounts
+            .owner_config
+            .is_authorized(&ctx.accounts.owner.key()),
+        TokenBridgeRelayerError::OwnerOrAssistantOnly
+    );
+
+    let expected_accounts = fees
+        .len()
+        .checked_mul(2)
+        .ok_or(TokenBridgeRelayerError::BatchLengthMismatch)?;
+    require!(
+        ctx.remaining_accounts.len() == expected_accounts,
+        TokenBridgeRelayerError::BatchLengthMismatch
+    );
+
+    for (i, &fee) in fees.iter().enumerate() {
+        let foreign_contract_info = &ctx.remaining_accounts[i * 2];
+
+        let mut foreign_contract = Account::<ForeignContract>::try_from(foreign_contract_info)
+            .map_err(|_| TokenBridgeRelayerError::InvalidForeignContractAccount)?;
+
+        let (expected_pda, _) = Pubkey::find_program_address(
+            &[
+                SEED_PREFIX_FOREIGN_CONTRACT,
+                &foreign_contract.chain.to_be_bytes()[..],
+            ],
+            ctx.program_id,
+        );
+        require!(
+            expected_pda == foreign_contract_info.key(),
+            TokenBridgeRelayerError::InvalidForeignContractAccount
+        );
+
+        require!(
+            foreign_contract.min_fee == 0 || fee >= foreign_contract.min_fee,
+            TokenBridgeRelayerError::FeeOutOfBounds
+        );
+        require!(
+            foreign_contract.max_fee == 0 || fee <= foreign_contract.max_fee,
+            TokenBridgeRelayerError::FeeOutOfBounds
+        );
+
+        let old_fee = foreign_contract.fee;
+        foreign_contract.fee = fee;
+        let chain = foreign_contract.chain;
+        foreign_contract.exit(ctx.program_id)?;
+
+        emit!(RelayerFeeChanged {
+            chain,
+            old_fee,
+            new_fee: fee,
+        });
+    }
+
+    Ok(())
+}
@@ -0,0 +1,100 @@
+use crate::{
+    error::TokenBridgeRelayerError,
+    events::SwapRateChanged,
+    state::{
+        AdminAuditLog, AuditLogConfig, OwnerConfig, RegisteredToken, SenderConfig,
+        AUDIT_ACTION_UPDATE_SWAP_RATE,
+    },
+    SEED_PREFIX_AUDIT_LOG, SEED_PREFIX_AUDIT_LOG_CONFIG, SEED_PREFIX_REGISTERED_TOKEN,
+    SEED_PREFIX_SENDER,
+};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct UpdateSwapRate<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    #[account(seeds = [b"owner_config"], bump)]
+    pub owner_config: Account<'info, OwnerConfig>,
+
+    #[account(
+        seeds = [SEED_PREFIX_SENDER],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, SenderConfig>,
+
+    /// CHECK: the mint whose swap rate is being updated; only used to derive the PDA.
+    pub mint: AccountInfo<'info>,
+
+    #[account(
+        mut,
+        seeds = [SEED_PREFIX_REGISTERED_TOKEN, mint.key().as_ref()],
+        bump,
+    )]
+    pub registered_token: Account<'info, RegisteredToken>,
+
+    #[account(
+        init_if_needed,
+        payer = owner,
+        seeds = [SEED_PREFIX_AUDIT_LOG_CONFIG],
+        bump,
+        space = AuditLogConfig::MAXIMUM_SIZE,
+    )]
+    pub audit_log_config: Account<'info, AuditLogConfig>,
+
+    #[account(
+        init,
+        payer = owner,
+        seeds = [SEED_PREFIX_AUDIT_LOG, &audit_log_config.counter.to_be_bytes()],
+        bump,
+        space = AdminAuditLog::MAXIMUM_SIZE,
+    )]
+    pub audit_log_entry: Account<'info, AdminAuditLog>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn update_swap_rate(ctx: Context<UpdateSwapRate>, swap_rate: u64) -> Result<()> {
+    require!(
+        ctx.accounts
+            .owner_config
+            .is_authorized(&ctx.accounts.owner.key()),
+        TokenBridgeRelayerError::OwnerOrAssistantOnly
+    );
+    require!(
+        swap_rate
+            >= RegisteredToken::min_valid_swap_rate(
+                ctx.accounts
+                    .registered_token
+                    .effective_swap_rate_precision()
+            ),
+        TokenBridgeRelayerError::SwapRateTooLow
+    );
+
+    let old_swap_rate = ctx.accounts.registered_token.swap_rate;
+    ctx.accounts.registered_token.swap_rate = swap_rate;
+    ctx.accounts.registered_token.last_swap_rate_update = Clock::get()?.slot;
+
+    ctx.accounts.audit_log_entry.record(
+        AUDIT_ACTION_UPDATE_SWAP_RATE,
+        ctx.accounts.owner.key(),
+        Some(ctx.accounts.mint.key()),
+        old_swap_rate,
+        swap_rate,
+    )?;
+    ctx.accounts.audit_log_config.counter = ctx
+        .accounts
+        .audit_log_config
+        .counter
+        .checked_add(1)
+        .ok_or(TokenBridgeRelayerError::InsufficientFunds)?;
+
+    emit!(SwapRateChanged {
+        mint: ctx.accounts.mint.key(),
+        old_swap_rate,
+        new_swap_rate: swap_rate,
+    });
+
+    Ok(())
+}
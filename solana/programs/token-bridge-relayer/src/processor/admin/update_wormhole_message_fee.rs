@@ -0,0 +1,26 @@
+use crate::{state::SenderConfig, SEED_PREFIX_SENDER};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct UpdateWormholeMessageFee<'info> {
+    pub owner: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [SEED_PREFIX_SENDER],
+        bump = config.bump,
+        has_one = owner,
+    )]
+    pub config: Account<'info, SenderConfig>,
+}
+
+/// Updates the cached Wormhole core bridge message fee the transfer-out instructions pre-check
+/// `payer`'s lamport balance against, since the fee is set by the Wormhole core bridge program
+/// and can change independently of this program.
+pub fn update_wormhole_message_fee(
+    ctx: Context<UpdateWormholeMessageFee>,
+    wormhole_message_fee: u64,
+) -> Result<()> {
+    ctx.accounts.config.wormhole_message_fee = wormhole_message_fee;
+    Ok(())
+}
@@ -0,0 +1,56 @@
+/// Token Bridge normalizes transferred amounts to 8 decimals of precision. These helpers mirror
+/// that behavior so relayer fee and swap-amount math stays consistent with the bridged amount
+/// that will actually show up in `tmp_token_account`.
+const MAX_DECIMALS: u8 = 8;
+
+pub fn normalize_amount(amount: u64, decimals: u8) -> u64 {
+    if decimals > MAX_DECIMALS {
+        amount / 10u64.pow((decimals - MAX_DECIMALS) as u32)
+    } else {
+        amount
+    }
+}
+
+pub fn denormalize_amount(amount: u64, decimals: u8) -> u64 {
+    if decimals > MAX_DECIMALS {
+        amount.saturating_mul(10u64.pow((decimals - MAX_DECIMALS) as u32))
+    } else {
+        amount
+    }
+}
+
+/// Rounds `amount` down to the precision Token Bridge will actually preserve, discarding any
+/// dust that would otherwise be lost during normalization.
+pub fn truncate_amount(amount: u64, decimals: u8) -> u64 {
+    denormalize_amount(normalize_amount(amount, decimals), decimals)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn normalize_amount_leaves_low_decimal_mints_untouched() {
+        assert_eq!(normalize_amount(1_234_567, 6), 1_234_567);
+        assert_eq!(normalize_amount(1_234_567, 8), 1_234_567);
+    }
+
+    #[test]
+    fn normalize_amount_drops_precision_beyond_8_decimals() {
+        assert_eq!(normalize_amount(1_234_567_890, 9), 123_456_789);
+        assert_eq!(normalize_amount(1_234_567_890_123, 12), 123_456_789);
+    }
+
+    #[test]
+    fn denormalize_amount_is_normalize_amounts_inverse_scale() {
+        assert_eq!(denormalize_amount(123_456_789, 9), 1_234_567_890);
+        assert_eq!(denormalize_amount(1_234_567, 6), 1_234_567);
+    }
+
+    #[test]
+    fn truncate_amount_zeroes_out_dust_below_8_decimal_precision() {
+        // 9 decimals: the last digit is dust that normalization can't preserve.
+        assert_eq!(truncate_amount(1_234_567_891, 9), 1_234_567_890);
+        assert_eq!(truncate_amount(1_234_567, 6), 1_234_567);
+    }
+}
@@ -0,0 +1,435 @@
+use crate::{
+    error::TokenBridgeRelayerError,
+    events::{
+        NativeMintWrappedPath, RelayerFeePaid, SwapExecuted, SwapSkippedInsufficientRelayerBalance,
+        SwapSkippedSlippage, TransferCompleted, WrappedDecimalsMismatch,
+    },
+    message::TokenBridgeRelayerMessage,
+    state::{
+        AllowedSourceChain, ChainStats, ForeignContract, PayerTransferHistory, ProgramStats,
+        RedeemerConfig, RegisteredToken, RelayerStats, RelayerWhitelist, SourceChainAllowlist,
+        TransferReceipt,
+    },
+    SEED_PREFIX_ALLOWED_CHAIN, SEED_PREFIX_CHAIN_STATS, SEED_PREFIX_PAYER_HISTORY,
+    SEED_PREFIX_PROGRAM_STATS, SEED_PREFIX_RECEIPT, SEED_PREFIX_REDEEMER,
+    SEED_PREFIX_REGISTERED_TOKEN, SEED_PREFIX_RELAYER_STATS, SEED_PREFIX_RELAYER_WHITELIST,
+    SEED_PREFIX_SOURCE_ALLOWLIST,
+};
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{self, Transfer as SystemTransfer};
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::token_interface::{
+    self, CloseAccount, Mint, TokenAccount, TokenInterface, TransferChecked,
+};
+
+#[derive(Accounts)]
+#[instruction(vaa_hash: [u8; 32])]
+pub struct CompleteWrappedTransferWithRelay<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    #[account(
+        seeds = [SEED_PREFIX_REDEEMER],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, RedeemerConfig>,
+
+    pub foreign_contract: Account<'info, ForeignContract>,
+
+    pub token_bridge_wrapped_mint: InterfaceAccount<'info, Mint>,
+
+    #[account(mut)]
+    pub recipient_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    /// CHECK: wallet that receives native SOL when the requested swap succeeds.
+    #[account(mut)]
+    pub recipient: AccountInfo<'info>,
+
+    #[account(mut)]
+    pub tmp_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    /// CHECK: validated against `config.fee_recipient`; only needed so
+    /// `fee_recipient_token_account`'s `associated_token::authority` constraint can derive its
+    /// address.
+    #[account(address = config.fee_recipient)]
+    pub fee_recipient: AccountInfo<'info>,
+
+    /// Created on demand if the fee recipient has never held this mint before, so redemption
+    /// doesn't fail (and the relayer gets stuck) just because the fee recipient's ATA doesn't
+    /// exist yet. `payer` (the relayer) fronts the rent; the relayer fee this account is about to
+    /// receive is expected to cover this occasional cost the same way it covers the rest of the
+    /// redemption's compute and transaction fees.
+    #[account(
+        init_if_needed,
+        payer = payer,
+        associated_token::mint = token_bridge_wrapped_mint,
+        associated_token::authority = fee_recipient,
+    )]
+    pub fee_recipient_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    /// CHECK: validated against `config.secondary_fee_recipient` below; only needed so
+    /// `secondary_fee_recipient_token_account`'s `associated_token::authority` constraint can
+    /// derive its address. Always required (even when `config.secondary_fee_recipient` is
+    /// `None`, in which case its value is unconstrained and unused) to keep this account's shape
+    /// stable across calls.
+    #[account(
+        constraint = config.secondary_fee_recipient.is_none()
+            || config.secondary_fee_recipient == Some(secondary_fee_recipient.key())
+            @ TokenBridgeRelayerError::InvalidSecondaryFeeRecipient,
+    )]
+    pub secondary_fee_recipient: AccountInfo<'info>,
+
+    /// Only credited when `config.secondary_fee_recipient` is set; otherwise unused.
+    #[account(
+        init_if_needed,
+        payer = payer,
+        associated_token::mint = token_bridge_wrapped_mint,
+        associated_token::authority = secondary_fee_recipient,
+    )]
+    pub secondary_fee_recipient_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        seeds = [SEED_PREFIX_REGISTERED_TOKEN, token_bridge_wrapped_mint.key().as_ref()],
+        bump,
+    )]
+    pub registered_token: Account<'info, RegisteredToken>,
+
+    #[account(
+        init_if_needed,
+        payer = payer,
+        seeds = [SEED_PREFIX_PROGRAM_STATS],
+        bump,
+        space = ProgramStats::MAXIMUM_SIZE,
+    )]
+    pub program_stats: Account<'info, ProgramStats>,
+
+    #[account(
+        init_if_needed,
+        payer = payer,
+        seeds = [SEED_PREFIX_CHAIN_STATS, &foreign_contract.chain.to_le_bytes()[..]],
+        bump,
+        space = ChainStats::MAXIMUM_SIZE,
+    )]
+    pub chain_stats: Account<'info, ChainStats>,
+
+    /// CHECK: whitelist entry for `payer`; only deserialized when `config.whitelist_enabled`.
+    #[account(
+        seeds = [SEED_PREFIX_RELAYER_WHITELIST, payer.key().as_ref()],
+        bump,
+    )]
+    pub relayer_whitelist: AccountInfo<'info>,
+
+    /// CHECK: singleton toggle for source-chain filtering; only deserialized when it has been
+    /// initialized by `enable_source_allowlist`/`disable_source_allowlist`. Filtering defaults
+    /// to disabled while it doesn't exist.
+    #[account(seeds = [SEED_PREFIX_SOURCE_ALLOWLIST], bump)]
+    pub source_chain_allowlist: AccountInfo<'info>,
+
+    /// CHECK: allowlist entry for `foreign_contract.chain`; only deserialized when
+    /// `source_chain_allowlist.enabled`.
+    #[account(seeds = [SEED_PREFIX_ALLOWED_CHAIN, &foreign_contract.chain.to_be_bytes()], bump)]
+    pub allowed_source_chain: AccountInfo<'info>,
+
+    /// Only updated when `payer` isn't also the recipient, i.e. this is a relayed redemption.
+    #[account(
+        init_if_needed,
+        payer = payer,
+        seeds = [SEED_PREFIX_RELAYER_STATS, payer.key().as_ref()],
+        bump,
+        space = RelayerStats::MAXIMUM_SIZE,
+    )]
+    pub relayer_stats: Account<'info, RelayerStats>,
+
+    #[account(
+        init,
+        payer = payer,
+        seeds = [SEED_PREFIX_RECEIPT, &vaa_hash],
+        bump,
+        space = TransferReceipt::MAXIMUM_SIZE,
+    )]
+    pub transfer_receipt: Account<'info, TransferReceipt>,
+
+    #[account(
+        init_if_needed,
+        payer = payer,
+        seeds = [SEED_PREFIX_PAYER_HISTORY, payer.key().as_ref()],
+        bump,
+        space = PayerTransferHistory::MAXIMUM_SIZE,
+    )]
+    pub payer_transfer_history: Account<'info, PayerTransferHistory>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn complete_wrapped_transfer_with_relay(
+    ctx: Context<CompleteWrappedTransferWithRelay>,
+    _vaa_hash: [u8; 32],
+) -> Result<()> {
+    require!(
+        !ctx.accounts.config.inbound_paused,
+        TokenBridgeRelayerError::InboundTransfersPaused
+    );
+
+    // No explicit registration check: `registered_token` is a `seeds`-constrained
+    // `Account<'info, RegisteredToken>`, so this instruction already fails before reaching this
+    // point if the mint was never registered (or was subsequently deregistered and closed).
+
+    if ctx.accounts.config.whitelist_enabled {
+        let whitelist = Account::<RelayerWhitelist>::try_from(&ctx.accounts.relayer_whitelist)
+            .map_err(|_| TokenBridgeRelayerError::RelayerNotWhitelisted)?;
+        require!(
+            whitelist.is_allowed,
+            TokenBridgeRelayerError::RelayerNotWhitelisted
+        );
+    }
+
+    if let Ok(allowlist) =
+        Account::<SourceChainAllowlist>::try_from(&ctx.accounts.source_chain_allowlist)
+    {
+        if allowlist.enabled {
+            let allowed_source_chain =
+                Account::<AllowedSourceChain>::try_from(&ctx.accounts.allowed_source_chain)
+                    .map_err(|_| TokenBridgeRelayerError::SourceChainNotAllowed)?;
+            require!(
+                allowed_source_chain.chain == ctx.accounts.foreign_contract.chain,
+                TokenBridgeRelayerError::SourceChainNotAllowed
+            );
+        }
+    }
+
+    // In the deployed program the Token Bridge `complete_transfer_wrapped_with_payload` CPI
+    // runs first, minting bridged tokens into `tmp_token_account`, and the payload is parsed
+    // from the resulting VAA to recover the relayer fee.
+    let message = TokenBridgeRelayerMessage::TransferWithRelayV4 {
+        target_relayer_fee: 0,
+        to_native_token_amount: 0,
+        recipient: [0u8; 32],
+        min_native_swap_output: 0,
+        reference_id: [0u8; 16],
+        memo: [0u8; 32],
+    };
+
+    let target_relayer_fee = message.target_relayer_fee();
+    let to_native_token_amount = message.to_native_token_amount();
+    let min_native_swap_output = message.min_native_swap_output();
+    let reference_id = message.reference_id();
+    let memo = message.memo();
+    msg!("transfer reference_id: {:?}", reference_id);
+    msg!("transfer memo: {:?}", memo);
+
+    let amount = ctx.accounts.tmp_token_account.amount;
+    require!(amount > 0, TokenBridgeRelayerError::ZeroBridgeAmount);
+
+    // No custody-balance safety net here, unlike `complete_native_transfer_with_relay`: a wrapped
+    // redemption mints new tokens via the Token Bridge program's mint authority rather than
+    // releasing them from a custody account, so there is no custody balance to check against.
+
+    ctx.accounts.registered_token.record_volume_out(amount);
+    ctx.accounts.program_stats.record_transfer_in();
+    ctx.accounts.chain_stats.chain = ctx.accounts.foreign_contract.chain;
+    ctx.accounts.chain_stats.record_transfer_in();
+    ctx.accounts
+        .payer_transfer_history
+        .record_inbound(amount, Clock::get()?.slot);
+
+    let (mut token_amount_in, mut native_amount_out) = (0u64, 0u64);
+    if to_native_token_amount > 0 {
+        let (calc_token_amount_in, calc_native_amount_out) = ctx
+            .accounts
+            .registered_token
+            .calculate_native_swap_amounts(
+                to_native_token_amount,
+                ctx.accounts.registered_token.decimals,
+                ctx.accounts
+                    .registered_token
+                    .effective_swap_rate_precision(),
+            )?;
+
+        let rent_minimum = Rent::get()?.minimum_balance(0);
+        if ctx.accounts.payer.lamports() < calc_native_amount_out.saturating_add(rent_minimum) {
+            emit!(SwapSkippedInsufficientRelayerBalance {
+                recipient: ctx.accounts.recipient.key(),
+                requested_native_amount: calc_native_amount_out,
+            });
+        } else if calc_native_amount_out < min_native_swap_output {
+            emit!(SwapSkippedSlippage {
+                recipient: ctx.accounts.recipient.key(),
+                computed_native_amount: calc_native_amount_out,
+                min_native_swap_output,
+            });
+        } else {
+            token_amount_in = calc_token_amount_in;
+            native_amount_out = calc_native_amount_out;
+        }
+    }
+
+    let recipient_amount = amount
+        .checked_sub(target_relayer_fee)
+        .and_then(|v| v.checked_sub(token_amount_in))
+        .ok_or(TokenBridgeRelayerError::InsufficientFunds)?;
+
+    let decimals = ctx.accounts.registered_token.decimals;
+
+    // Denormalization below uses `registered_token.decimals` (captured at `register_token` time),
+    // not `token_bridge_wrapped_mint.decimals` directly. The two are expected to always agree,
+    // but nothing re-derives `registered_token.decimals` from the live mint on every redemption,
+    // so a mint whose decimals changed (or were recorded wrong at registration) would silently
+    // mis-denormalize every transfer without this check.
+    if decimals != ctx.accounts.token_bridge_wrapped_mint.decimals {
+        emit!(WrappedDecimalsMismatch {
+            mint: ctx.accounts.token_bridge_wrapped_mint.key(),
+            registered_decimals: decimals,
+            mint_decimals: ctx.accounts.token_bridge_wrapped_mint.decimals,
+        });
+    }
+
+    // `token_bridge_wrapped_mint` being native SOL's wrapped mint means the bridged asset is WSOL,
+    // not a foreign token that happens to be wrapped. There is no existing native-SOL unwrap logic
+    // elsewhere in this program to mirror here (`complete_native_transfer_with_relay` only ever
+    // moves the redeemed mint's own SPL tokens); this is new.
+    let is_native_mint = ctx.accounts.token_bridge_wrapped_mint.key()
+        == token_interface::spl_token_2022::native_mint::ID;
+
+    if recipient_amount > 0 && !is_native_mint {
+        token_interface::transfer_checked(
+            CpiContext::new(
+                ctx.accounts.token_program.to_account_info(),
+                TransferChecked {
+                    from: ctx.accounts.tmp_token_account.to_account_info(),
+                    mint: ctx.accounts.token_bridge_wrapped_mint.to_account_info(),
+                    to: ctx.accounts.recipient_token_account.to_account_info(),
+                    authority: ctx.accounts.config.to_account_info(),
+                },
+            ),
+            recipient_amount,
+            decimals,
+        )?;
+    }
+
+    let relayer_token_amount = target_relayer_fee.saturating_add(token_amount_in);
+    let (primary_fee_amount, secondary_fee_amount) =
+        if ctx.accounts.config.secondary_fee_recipient.is_some() {
+            let secondary_bps = 10_000u128 - ctx.accounts.config.fee_split_bps as u128;
+            let secondary_amount = (relayer_token_amount as u128 * secondary_bps / 10_000) as u64;
+            let primary_amount = relayer_token_amount
+                .checked_sub(secondary_amount)
+                .ok_or(TokenBridgeRelayerError::InsufficientFunds)?;
+            (primary_amount, secondary_amount)
+        } else {
+            (relayer_token_amount, 0)
+        };
+
+    if primary_fee_amount > 0 {
+        token_interface::transfer_checked(
+            CpiContext::new(
+                ctx.accounts.token_program.to_account_info(),
+                TransferChecked {
+                    from: ctx.accounts.tmp_token_account.to_account_info(),
+                    mint: ctx.accounts.token_bridge_wrapped_mint.to_account_info(),
+                    to: ctx.accounts.fee_recipient_token_account.to_account_info(),
+                    authority: ctx.accounts.config.to_account_info(),
+                },
+            ),
+            primary_fee_amount,
+            decimals,
+        )?;
+
+        emit!(RelayerFeePaid {
+            recipient: ctx.accounts.recipient.key(),
+            fee_recipient: ctx.accounts.fee_recipient.key(),
+            fee_amount: primary_fee_amount,
+        });
+    }
+
+    if secondary_fee_amount > 0 {
+        token_interface::transfer_checked(
+            CpiContext::new(
+                ctx.accounts.token_program.to_account_info(),
+                TransferChecked {
+                    from: ctx.accounts.tmp_token_account.to_account_info(),
+                    mint: ctx.accounts.token_bridge_wrapped_mint.to_account_info(),
+                    to: ctx
+                        .accounts
+                        .secondary_fee_recipient_token_account
+                        .to_account_info(),
+                    authority: ctx.accounts.config.to_account_info(),
+                },
+            ),
+            secondary_fee_amount,
+            decimals,
+        )?;
+    }
+
+    if is_native_mint && recipient_amount > 0 {
+        // The fee transfers above have drained `tmp_token_account` down to exactly
+        // `recipient_amount`. A WSOL token account's lamports are the unwrapped SOL, so closing it
+        // straight to `recipient` delivers that amount (plus the account's rent-exempt reserve) as
+        // ordinary SOL, with no separate unwrap step required.
+        let config_seeds: &[&[u8]] = &[SEED_PREFIX_REDEEMER, &[ctx.accounts.config.bump]];
+        token_interface::close_account(CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            CloseAccount {
+                account: ctx.accounts.tmp_token_account.to_account_info(),
+                destination: ctx.accounts.recipient.to_account_info(),
+                authority: ctx.accounts.config.to_account_info(),
+            },
+            &[config_seeds],
+        ))?;
+
+        emit!(NativeMintWrappedPath {
+            recipient: ctx.accounts.recipient.key(),
+            amount: recipient_amount,
+        });
+    }
+
+    if ctx.accounts.payer.key() != ctx.accounts.recipient.key() {
+        ctx.accounts.relayer_stats.record_earnings(
+            relayer_token_amount,
+            native_amount_out,
+            Clock::get()?.slot,
+        );
+    }
+
+    if native_amount_out > 0 {
+        system_program::transfer(
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                SystemTransfer {
+                    from: ctx.accounts.payer.to_account_info(),
+                    to: ctx.accounts.recipient.to_account_info(),
+                },
+            ),
+            native_amount_out,
+        )?;
+
+        emit!(SwapExecuted {
+            recipient: ctx.accounts.recipient.key(),
+            relayer: ctx.accounts.payer.key(),
+            token: ctx.accounts.token_bridge_wrapped_mint.key(),
+            token_amount_in,
+            native_amount_out,
+        });
+    }
+
+    ctx.accounts.transfer_receipt.set_inner(TransferReceipt {
+        redeemed_at_slot: Clock::get()?.slot,
+        redeemer: ctx.accounts.payer.key(),
+        recipient: ctx.accounts.recipient.key(),
+        token_amount: recipient_amount,
+        native_swap_out: native_amount_out,
+        fee_paid: relayer_token_amount,
+    });
+
+    emit!(TransferCompleted {
+        recipient: ctx.accounts.recipient.key(),
+        amount,
+        reference_id,
+        memo,
+        recipient_ata_created: false,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,85 @@
+use crate::{
+    state::{ForeignContract, RedeemerConfig, RegisteredToken},
+    SEED_PREFIX_FOREIGN_CONTRACT, SEED_PREFIX_REDEEMER, SEED_PREFIX_REGISTERED_TOKEN,
+};
+use anchor_lang::prelude::*;
+
+/// Result account written by [`get_relayer_fee_quote`], left open (unlike the ephemeral
+/// `FeeEstimate`/`SwapPreview` accounts in `query.rs`) so a calling program can CPI into this
+/// instruction and then read the quote straight out of the account it provided, without relying
+/// on transaction simulation.
+#[account]
+#[derive(Default)]
+pub struct FeeQuote {
+    pub relayer_fee_tokens: u64,
+    pub to_native_token_amount_max: u64,
+    pub total_cost_tokens: u64,
+}
+
+impl FeeQuote {
+    pub const MAXIMUM_SIZE: usize = 8 + 8 + 8 + 8;
+}
+
+#[derive(Accounts)]
+#[instruction(chain: u16)]
+pub struct GetRelayerFeeQuote<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    #[account(
+        seeds = [SEED_PREFIX_REDEEMER],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, RedeemerConfig>,
+
+    #[account(
+        seeds = [SEED_PREFIX_FOREIGN_CONTRACT, &chain.to_be_bytes()[..]],
+        bump,
+    )]
+    pub foreign_contract: Account<'info, ForeignContract>,
+
+    pub mint: Account<'info, anchor_spl::token::Mint>,
+
+    #[account(
+        seeds = [SEED_PREFIX_REGISTERED_TOKEN, mint.key().as_ref()],
+        bump,
+    )]
+    pub registered_token: Account<'info, RegisteredToken>,
+
+    #[account(
+        init,
+        payer = payer,
+        space = FeeQuote::MAXIMUM_SIZE,
+    )]
+    pub fee_quote: Account<'info, FeeQuote>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// CPI-friendly quote of what `complete_native_transfer_with_relay` /
+/// `complete_wrapped_transfer_with_relay` would charge to redeem `amount` of `mint` from `chain`,
+/// so other Solana programs can size a bridge transfer without duplicating the fee formula.
+/// `amount` isn't used yet since the relayer fee is currently flat per chain, but is part of the
+/// interface so a future amount-scaled fee doesn't need a breaking change here.
+pub fn get_relayer_fee_quote(
+    ctx: Context<GetRelayerFeeQuote>,
+    _chain: u16,
+    _mint: Pubkey,
+    _amount: u64,
+) -> Result<()> {
+    let relayer_fee_tokens = ctx.accounts.foreign_contract.checked_token_fee(
+        ctx.accounts.registered_token.decimals,
+        ctx.accounts.registered_token.swap_rate,
+        ctx.accounts.config.relayer_fee_precision,
+    )?;
+    let to_native_token_amount_max = ctx.accounts.registered_token.max_native_swap_amount;
+    let total_cost_tokens = relayer_fee_tokens.saturating_add(to_native_token_amount_max);
+
+    ctx.accounts.fee_quote.set_inner(FeeQuote {
+        relayer_fee_tokens,
+        to_native_token_amount_max,
+        total_cost_tokens,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,23 @@
+mod admin;
+pub use admin::*;
+
+mod amount;
+pub use amount::*;
+
+mod transfer_native_tokens_with_relay;
+pub use transfer_native_tokens_with_relay::*;
+
+mod transfer_wrapped_tokens_with_relay;
+pub use transfer_wrapped_tokens_with_relay::*;
+
+mod complete_native_transfer_with_relay;
+pub use complete_native_transfer_with_relay::*;
+
+mod complete_wrapped_transfer_with_relay;
+pub use complete_wrapped_transfer_with_relay::*;
+
+mod query;
+pub use query::*;
+
+mod fee_quoter;
+pub use fee_quoter::*;
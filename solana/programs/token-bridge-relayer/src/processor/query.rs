@@ -0,0 +1,555 @@
+use crate::{
+    error::TokenBridgeRelayerError,
+    events::{
+        AccountSizeReport, AmountNormalized, FeeSnapshot, HealthCheckPassed, ProgramVersionRead,
+        SequenceGapDetected, SwapPreviewCalculated, TokenRegistrySnapshot,
+    },
+    processor::{normalize_amount, truncate_amount},
+    state::{
+        ForeignContract, OwnerConfig, ProgramVersion, ProgramVersionConfig, RedeemerConfig,
+        RegisteredToken, SenderConfig, SignerSequence, TokenRegistry,
+    },
+    SEED_PREFIX_FEE_QUERY, SEED_PREFIX_FOREIGN_CONTRACT, SEED_PREFIX_PROGRAM_VERSION_CONFIG,
+    SEED_PREFIX_REDEEMER, SEED_PREFIX_REGISTERED_TOKEN, SEED_PREFIX_SENDER,
+    SEED_PREFIX_SIGNER_SEQUENCE, SEED_PREFIX_TOKEN_REGISTRY,
+};
+use anchor_lang::prelude::*;
+
+/// Maximum mints emitted per [`TokenRegistrySnapshot`] event, keeping each event well under the
+/// transaction log size limit regardless of how many tokens are registered.
+const TOKEN_REGISTRY_CHUNK_SIZE: usize = 32;
+
+/// Ephemeral account written by [`compute_relayer_fee`] so an off-chain client can read the
+/// computed fee out of the transaction simulation response. Solana instructions can't return
+/// values directly, so this is opened and closed within the same instruction.
+#[account]
+#[derive(Default)]
+pub struct FeeEstimate {
+    pub token_fee: u64,
+}
+
+impl FeeEstimate {
+    pub const MAXIMUM_SIZE: usize = 8 + 8;
+}
+
+#[derive(Accounts)]
+#[instruction(chain: u16)]
+pub struct ComputeRelayerFee<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    #[account(
+        seeds = [SEED_PREFIX_REDEEMER],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, RedeemerConfig>,
+
+    #[account(
+        seeds = [SEED_PREFIX_FOREIGN_CONTRACT, &chain.to_be_bytes()[..]],
+        bump,
+    )]
+    pub foreign_contract: Account<'info, ForeignContract>,
+
+    pub mint: Account<'info, anchor_spl::token::Mint>,
+
+    #[account(
+        seeds = [SEED_PREFIX_REGISTERED_TOKEN, mint.key().as_ref()],
+        bump,
+    )]
+    pub registered_token: Account<'info, RegisteredToken>,
+
+    #[account(
+        init,
+        payer = payer,
+        space = FeeEstimate::MAXIMUM_SIZE,
+    )]
+    pub fee_estimate: Account<'info, FeeEstimate>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn compute_relayer_fee(
+    ctx: Context<ComputeRelayerFee>,
+    _chain: u16,
+    decimals: u8,
+) -> Result<()> {
+    let token_fee = ctx.accounts.foreign_contract.checked_token_fee(
+        decimals,
+        ctx.accounts.registered_token.swap_rate,
+        ctx.accounts.config.relayer_fee_precision,
+    )?;
+
+    msg!("relayer fee: {} raw token units", token_fee);
+    ctx.accounts.fee_estimate.token_fee = token_fee;
+
+    ctx.accounts
+        .fee_estimate
+        .close(ctx.accounts.payer.to_account_info())?;
+
+    Ok(())
+}
+
+/// Ephemeral account written by [`compute_swap_preview`] so an off-chain client can read the
+/// computed native swap amounts out of the transaction simulation response, mirroring
+/// [`FeeEstimate`].
+#[account]
+#[derive(Default)]
+pub struct SwapPreview {
+    pub token_amount_in: u64,
+    pub native_amount_out: u64,
+}
+
+impl SwapPreview {
+    pub const MAXIMUM_SIZE: usize = 8 + 8 + 8;
+}
+
+#[derive(Accounts)]
+pub struct ComputeSwapPreview<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    #[account(
+        seeds = [SEED_PREFIX_REDEEMER],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, RedeemerConfig>,
+
+    pub mint: Account<'info, anchor_spl::token::Mint>,
+
+    #[account(
+        seeds = [SEED_PREFIX_REGISTERED_TOKEN, mint.key().as_ref()],
+        bump,
+    )]
+    pub registered_token: Account<'info, RegisteredToken>,
+
+    #[account(
+        init,
+        payer = payer,
+        space = SwapPreview::MAXIMUM_SIZE,
+    )]
+    pub swap_preview: Account<'info, SwapPreview>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Previews the native swap amounts `complete_native_transfer_with_relay` /
+/// `complete_wrapped_transfer_with_relay` would produce for `to_native_token_amount`, without
+/// requiring a redemption. There is no separate `RegisteredToken` for native SOL in this
+/// program, so the swap rate is read straight from `registered_token`, the same account the
+/// complete-transfer instructions use.
+pub fn compute_swap_preview(
+    ctx: Context<ComputeSwapPreview>,
+    decimals: u8,
+    to_native_token_amount: u64,
+) -> Result<()> {
+    let (token_amount_in, native_amount_out) = ctx
+        .accounts
+        .registered_token
+        .calculate_native_swap_amounts(
+            to_native_token_amount,
+            decimals,
+            ctx.accounts
+                .registered_token
+                .effective_swap_rate_precision(),
+        )?;
+
+    ctx.accounts.swap_preview.token_amount_in = token_amount_in;
+    ctx.accounts.swap_preview.native_amount_out = native_amount_out;
+
+    emit!(SwapPreviewCalculated {
+        token_amount_in,
+        native_amount_out,
+    });
+
+    ctx.accounts
+        .swap_preview
+        .close(ctx.accounts.payer.to_account_info())?;
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct GetTokenRegistry<'info> {
+    #[account(seeds = [SEED_PREFIX_TOKEN_REGISTRY], bump)]
+    pub token_registry: Account<'info, TokenRegistry>,
+}
+
+/// Emits every registered mint as a series of `TokenRegistrySnapshot` events, chunked so a
+/// large registry doesn't overflow a single event's size, so off-chain relayer software can
+/// discover registered tokens from a transaction simulation instead of scanning all program
+/// accounts.
+pub fn get_token_registry(ctx: Context<GetTokenRegistry>) -> Result<()> {
+    let mints = &ctx.accounts.token_registry.mints;
+    let count = mints.len() as u64;
+
+    if mints.is_empty() {
+        emit!(TokenRegistrySnapshot {
+            count,
+            chunk_index: 0,
+            chunk: Vec::new(),
+        });
+        return Ok(());
+    }
+
+    for (chunk_index, chunk) in mints.chunks(TOKEN_REGISTRY_CHUNK_SIZE).enumerate() {
+        emit!(TokenRegistrySnapshot {
+            count,
+            chunk_index: chunk_index as u32,
+            chunk: chunk.to_vec(),
+        });
+    }
+
+    Ok(())
+}
+
+/// Ephemeral account written by [`normalize_transfer_amount`] so an off-chain client can read
+/// the normalization math out of the transaction simulation response, mirroring [`FeeEstimate`].
+#[account]
+#[derive(Default)]
+pub struct NormalizeResult {
+    pub normalized: u64,
+    pub truncated: u64,
+    pub residual: u64,
+}
+
+impl NormalizeResult {
+    pub const MAXIMUM_SIZE: usize = 8 + 8 + 8 + 8;
+}
+
+#[derive(Accounts)]
+pub struct NormalizeTransferAmount<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    #[account(
+        init,
+        payer = payer,
+        space = NormalizeResult::MAXIMUM_SIZE,
+    )]
+    pub normalize_result: Account<'info, NormalizeResult>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Translates a human-readable `amount` into the 8-decimal normalized amount Token Bridge
+/// actually transfers, including the truncation dust a sender would otherwise lose, so a front
+/// end doesn't have to reimplement `processor::normalize_amount` / `processor::truncate_amount`.
+pub fn normalize_transfer_amount(
+    ctx: Context<NormalizeTransferAmount>,
+    amount: u64,
+    decimals: u8,
+) -> Result<()> {
+    let normalized = normalize_amount(amount, decimals);
+    let truncated = truncate_amount(amount, decimals);
+    let residual = amount.saturating_sub(truncated);
+
+    ctx.accounts.normalize_result.normalized = normalized;
+    ctx.accounts.normalize_result.truncated = truncated;
+    ctx.accounts.normalize_result.residual = residual;
+
+    emit!(AmountNormalized {
+        normalized,
+        truncated,
+        residual,
+    });
+
+    ctx.accounts
+        .normalize_result
+        .close(ctx.accounts.payer.to_account_info())?;
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct QueryAllFees<'info> {
+    pub payer: Signer<'info>,
+}
+
+/// Emits a `FeeSnapshot` for every `ForeignContract` in `ctx.remaining_accounts`, so an
+/// off-chain relayer can read every chain's fee out of a single transaction simulation response
+/// instead of one `getAccountInfo` call per chain. An account that isn't a valid
+/// `ForeignContract` PDA for its own `chain` field is skipped (with a log) rather than failing
+/// the whole query, since a stale or malformed entry in the caller-supplied list shouldn't block
+/// reading the rest of them.
+pub fn query_all_fees(ctx: Context<QueryAllFees>) -> Result<()> {
+    for foreign_contract_info in ctx.remaining_accounts {
+        let foreign_contract = match Account::<ForeignContract>::try_from(foreign_contract_info) {
+            Ok(foreign_contract) => foreign_contract,
+            Err(_) => {
+                msg!(
+                    "query_all_fees: skipping {}, not a ForeignContract account",
+                    foreign_contract_info.key()
+                );
+                continue;
+            }
+        };
+
+        let (expected_pda, _) = Pubkey::find_program_address(
+            &[
+                SEED_PREFIX_FOREIGN_CONTRACT,
+                &foreign_contract.chain.to_be_bytes()[..],
+            ],
+            ctx.program_id,
+        );
+        if expected_pda != foreign_contract_info.key() {
+            msg!(
+                "query_all_fees: skipping {}, not the ForeignContract PDA for chain {}",
+                foreign_contract_info.key(),
+                foreign_contract.chain
+            );
+            continue;
+        }
+
+        emit!(FeeSnapshot {
+            chain: foreign_contract.chain,
+            fee: foreign_contract.fee,
+        });
+    }
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct GetProgramVersion<'info> {
+    pub program_version: Account<'info, ProgramVersion>,
+}
+
+/// Emits the deployed version out of `program_version` so off-chain clients can read it from a
+/// transaction simulation response instead of fetching and deserializing the PDA themselves.
+pub fn get_program_version(ctx: Context<GetProgramVersion>) -> Result<()> {
+    let program_version = &ctx.accounts.program_version;
+
+    emit!(ProgramVersionRead {
+        major: program_version.major,
+        minor: program_version.minor,
+        patch: program_version.patch,
+        deploy_slot: program_version.deploy_slot,
+        deployer: program_version.deployer,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct ValidateAccountSizes<'info> {
+    #[account(seeds = [SEED_PREFIX_SENDER], bump)]
+    pub sender_config: Account<'info, SenderConfig>,
+
+    #[account(seeds = [SEED_PREFIX_REDEEMER], bump)]
+    pub redeemer_config: Account<'info, RedeemerConfig>,
+
+    #[account(seeds = [b"owner_config"], bump)]
+    pub owner_config: Account<'info, OwnerConfig>,
+
+    #[account(seeds = [SEED_PREFIX_PROGRAM_VERSION_CONFIG], bump)]
+    pub program_version_config: Account<'info, ProgramVersionConfig>,
+}
+
+/// Emits an `AccountSizeReport` for each singleton config account, comparing its actual on-chain
+/// data length against the `MAXIMUM_SIZE` the current program build expects. Useful right after
+/// an upgrade to confirm no account was left with a stale layout.
+pub fn validate_account_sizes(ctx: Context<ValidateAccountSizes>) -> Result<()> {
+    emit!(AccountSizeReport {
+        account: ctx.accounts.sender_config.key(),
+        actual_size: ctx.accounts.sender_config.to_account_info().data_len() as u64,
+        expected_size: SenderConfig::MAXIMUM_SIZE as u64,
+    });
+
+    emit!(AccountSizeReport {
+        account: ctx.accounts.redeemer_config.key(),
+        actual_size: ctx.accounts.redeemer_config.to_account_info().data_len() as u64,
+        expected_size: RedeemerConfig::MAXIMUM_SIZE as u64,
+    });
+
+    emit!(AccountSizeReport {
+        account: ctx.accounts.owner_config.key(),
+        actual_size: ctx.accounts.owner_config.to_account_info().data_len() as u64,
+        expected_size: OwnerConfig::MAXIMUM_SIZE as u64,
+    });
+
+    emit!(AccountSizeReport {
+        account: ctx.accounts.program_version_config.key(),
+        actual_size: ctx
+            .accounts
+            .program_version_config
+            .to_account_info()
+            .data_len() as u64,
+        expected_size: ProgramVersionConfig::MAXIMUM_SIZE as u64,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct HealthCheck<'info> {
+    pub payer: Signer<'info>,
+
+    #[account(seeds = [SEED_PREFIX_SENDER], bump = sender_config.bump)]
+    pub sender_config: Account<'info, SenderConfig>,
+
+    #[account(seeds = [SEED_PREFIX_REDEEMER], bump = redeemer_config.bump)]
+    pub redeemer_config: Account<'info, RedeemerConfig>,
+
+    #[account(seeds = [b"owner_config"], bump)]
+    pub owner_config: Account<'info, OwnerConfig>,
+}
+
+/// RPC-callable liveness/consistency check for monitoring infrastructure (e.g. Datadog,
+/// PagerDuty): confirms `SenderConfig`, `RedeemerConfig`, and `OwnerConfig` all agree on the
+/// program's owner and relayer fee precision, then emits `HealthCheckPassed` so a poller reading
+/// the transaction simulation response doesn't have to fetch and cross-check the three accounts
+/// itself. Failing the assertions instead of silently reporting a mismatch ensures monitoring
+/// treats state divergence as an alertable failure rather than a value to graph.
+pub fn health_check(ctx: Context<HealthCheck>) -> Result<()> {
+    require!(
+        ctx.accounts.sender_config.owner == ctx.accounts.redeemer_config.owner,
+        TokenBridgeRelayerError::StateInconsistency
+    );
+    require!(
+        ctx.accounts.sender_config.relayer_fee_precision
+            == ctx.accounts.redeemer_config.relayer_fee_precision,
+        TokenBridgeRelayerError::StateInconsistency
+    );
+    require!(
+        ctx.accounts.owner_config.owner == ctx.accounts.sender_config.owner,
+        TokenBridgeRelayerError::StateInconsistency
+    );
+
+    emit!(HealthCheckPassed {
+        slot: Clock::get()?.slot,
+        paused: ctx.accounts.sender_config.paused,
+        fee_precision: ctx.accounts.sender_config.relayer_fee_precision,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct DetectSequenceGap<'info> {
+    /// CHECK: the payer whose `SignerSequence` is being checked; only used to derive seeds.
+    pub payer: AccountInfo<'info>,
+
+    #[account(
+        seeds = [SEED_PREFIX_SIGNER_SEQUENCE, payer.key().as_ref()],
+        bump,
+    )]
+    pub signer_sequence: Account<'info, SignerSequence>,
+}
+
+/// Compares `signer_sequence.value` against `signer_sequence.last_committed_sequence` and emits
+/// `SequenceGapDetected` if they diverge, indicating a transfer upticked the sequence but never
+/// completed its Token Bridge CPI. The genuine signal for this is a mismatch between this and
+/// the Wormhole program's own per-emitter `SequenceTracker`, but that program isn't a dependency
+/// of this crate, so this program-local pair of counters stands in as the same divergence check.
+pub fn detect_sequence_gap(ctx: Context<DetectSequenceGap>) -> Result<()> {
+    let signer_sequence = &ctx.accounts.signer_sequence;
+
+    if signer_sequence.value != signer_sequence.last_committed_sequence {
+        emit!(SequenceGapDetected {
+            payer: ctx.accounts.payer.key(),
+            expected: signer_sequence.last_committed_sequence,
+            actual: signer_sequence.value,
+        });
+    }
+
+    Ok(())
+}
+
+/// Result account written by [`query_token_fee`], seeded per payer (unlike the ephemeral
+/// `FeeEstimate`/`SwapPreview` accounts above and the per-call `FeeQuote` in `fee_quoter.rs`) so
+/// a calling program can CPI into this instruction once, hold on to the same PDA across
+/// transactions, and read the fee straight out of the account it controls without relying on
+/// transaction simulation. Explicitly closed via [`close_fee_query_result`] once the caller is
+/// done with it.
+#[account]
+#[derive(Default)]
+pub struct FeeQueryResult {
+    pub token_fee: u64,
+    pub normalized_fee: u64,
+    pub chain: u16,
+    pub mint: Pubkey,
+}
+
+impl FeeQueryResult {
+    pub const MAXIMUM_SIZE: usize = 8 + 8 + 8 + 2 + 32;
+}
+
+#[derive(Accounts)]
+#[instruction(chain: u16)]
+pub struct QueryTokenFee<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    #[account(
+        seeds = [SEED_PREFIX_SENDER],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, SenderConfig>,
+
+    #[account(
+        seeds = [SEED_PREFIX_FOREIGN_CONTRACT, &chain.to_be_bytes()[..]],
+        bump,
+    )]
+    pub foreign_contract: Account<'info, ForeignContract>,
+
+    pub mint: Account<'info, anchor_spl::token::Mint>,
+
+    #[account(
+        seeds = [SEED_PREFIX_REGISTERED_TOKEN, mint.key().as_ref()],
+        bump,
+    )]
+    pub registered_token: Account<'info, RegisteredToken>,
+
+    #[account(
+        init_if_needed,
+        payer = payer,
+        seeds = [SEED_PREFIX_FEE_QUERY, payer.key().as_ref()],
+        bump,
+        space = FeeQueryResult::MAXIMUM_SIZE,
+    )]
+    pub fee_query_result: Account<'info, FeeQueryResult>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// CPI-callable counterpart to [`compute_relayer_fee`], meant for other Solana programs (e.g. a
+/// DEX aggregator routing through this relayer) rather than transaction simulation: the result
+/// is written to a payer-seeded PDA the caller can read back after the CPI returns, instead of an
+/// account that's opened and closed within a single instruction.
+pub fn query_token_fee(ctx: Context<QueryTokenFee>, chain: u16) -> Result<()> {
+    let token_fee = ctx.accounts.foreign_contract.checked_token_fee(
+        ctx.accounts.registered_token.decimals,
+        ctx.accounts.registered_token.swap_rate,
+        ctx.accounts.config.relayer_fee_precision,
+    )?;
+    let normalized_fee = normalize_amount(token_fee, ctx.accounts.registered_token.decimals);
+
+    ctx.accounts.fee_query_result.set_inner(FeeQueryResult {
+        token_fee,
+        normalized_fee,
+        chain,
+        mint: ctx.accounts.mint.key(),
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct CloseFeeQueryResult<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    #[account(
+        mut,
+        close = payer,
+        seeds = [SEED_PREFIX_FEE_QUERY, payer.key().as_ref()],
+        bump,
+    )]
+    pub fee_query_result: Account<'info, FeeQueryResult>,
+}
+
+/// Closes a [`FeeQueryResult`] once the calling program is done reading it, releasing its rent
+/// back to `payer`.
+pub fn close_fee_query_result(_ctx: Context<CloseFeeQueryResult>) -> Result<()> {
+    Ok(())
+}
@@ -0,0 +1,558 @@
+use crate::{
+    error::TokenBridgeRelayerError,
+    events::{LargeTransferWarning, MaxFeeCapApplied, TruncationResidualRefunded},
+    processor::normalize_amount,
+    state::{
+        ChainStats, ChainVolumeLimit, CircuitBreaker, EpochFeeSchedule, ForeignContract,
+        PayerTransferHistory, ProgramStats, RecipientBlacklist, RegisteredToken, SenderConfig,
+        SignerSequence, SupportedChain, SupportedChainsConfig, WalletRateLimit,
+    },
+    utils::validate_evm_recipient,
+    ESTIMATED_TRANSACTION_FEE_LAMPORTS, SEED_PREFIX_CHAIN_STATS, SEED_PREFIX_CHAIN_VOLUME_LIMIT,
+    SEED_PREFIX_CIRCUIT_BREAKER, SEED_PREFIX_EPOCH_FEE_SCHEDULE, SEED_PREFIX_FOREIGN_CONTRACT,
+    SEED_PREFIX_PAYER_HISTORY, SEED_PREFIX_PROGRAM_STATS, SEED_PREFIX_RECIPIENT_BLACKLIST,
+    SEED_PREFIX_REGISTERED_TOKEN, SEED_PREFIX_SENDER, SEED_PREFIX_SIGNER_SEQUENCE,
+    SEED_PREFIX_SUPPORTED_CHAIN, SEED_PREFIX_SUPPORTED_CHAINS_CONFIG, SEED_PREFIX_TMP,
+    SEED_PREFIX_WALLET_RATE_LIMIT,
+};
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{self, Mint, TokenAccount, TokenInterface, TransferChecked};
+
+#[derive(Accounts)]
+#[instruction(amount: u64, to_native_token_amount: u64, recipient_chain: u16)]
+pub struct TransferNativeTokensWithRelay<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    #[account(
+        seeds = [SEED_PREFIX_SENDER],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, SenderConfig>,
+
+    #[account(
+        seeds = [SEED_PREFIX_FOREIGN_CONTRACT, &recipient_chain.to_be_bytes()[..]],
+        bump,
+    )]
+    pub foreign_contract: Account<'info, ForeignContract>,
+
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    #[account(mut)]
+    pub from_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    /// `constraint` is redundant with `init` creating this account fresh on every call, but
+    /// guards against a PDA-collision exploit slipping a pre-funded account past `init` (e.g. an
+    /// account already owned by the token program with a nonzero balance sitting at this address
+    /// for some reason `init` doesn't itself reject).
+    #[account(
+        init,
+        payer = payer,
+        seeds = [SEED_PREFIX_TMP, mint.key().as_ref()],
+        bump,
+        token::mint = mint,
+        token::authority = config,
+        constraint = tmp_token_account.amount == 0 @ TokenBridgeRelayerError::TmpAccountNotEmpty,
+    )]
+    pub tmp_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        seeds = [SEED_PREFIX_REGISTERED_TOKEN, mint.key().as_ref()],
+        bump,
+    )]
+    pub registered_token: Account<'info, RegisteredToken>,
+
+    #[account(
+        init_if_needed,
+        payer = payer,
+        seeds = [SEED_PREFIX_SIGNER_SEQUENCE, payer.key().as_ref()],
+        bump,
+        space = SignerSequence::MAXIMUM_SIZE,
+    )]
+    pub signer_sequence: Account<'info, SignerSequence>,
+
+    #[account(
+        init_if_needed,
+        payer = payer,
+        seeds = [SEED_PREFIX_PROGRAM_STATS],
+        bump,
+        space = ProgramStats::MAXIMUM_SIZE,
+    )]
+    pub program_stats: Account<'info, ProgramStats>,
+
+    #[account(
+        init_if_needed,
+        payer = payer,
+        seeds = [SEED_PREFIX_CHAIN_STATS, &recipient_chain.to_le_bytes()[..]],
+        bump,
+        space = ChainStats::MAXIMUM_SIZE,
+    )]
+    pub chain_stats: Account<'info, ChainStats>,
+
+    #[account(
+        init_if_needed,
+        payer = payer,
+        seeds = [SEED_PREFIX_WALLET_RATE_LIMIT, payer.key().as_ref()],
+        bump,
+        space = WalletRateLimit::MAXIMUM_SIZE,
+    )]
+    pub wallet_rate_limit: Account<'info, WalletRateLimit>,
+
+    #[account(
+        init_if_needed,
+        payer = payer,
+        seeds = [SEED_PREFIX_PAYER_HISTORY, payer.key().as_ref()],
+        bump,
+        space = PayerTransferHistory::MAXIMUM_SIZE,
+    )]
+    pub payer_transfer_history: Account<'info, PayerTransferHistory>,
+
+    #[account(
+        init_if_needed,
+        payer = payer,
+        seeds = [SEED_PREFIX_CIRCUIT_BREAKER],
+        bump,
+        space = CircuitBreaker::MAXIMUM_SIZE,
+    )]
+    pub circuit_breaker: Account<'info, CircuitBreaker>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Validates the requested relayer fee and native-swap amount against the transfer amount and
+/// returns the (normalized) amount the recipient will actually receive after fees and swap
+/// deduction. `amount` and `to_native_token_amount` are denominated in the mint's raw units.
+/// `relayer_fee` is a USD amount (scaled by `SenderConfig::relayer_fee_precision`) unless
+/// `fee_in_token_units` is set, in which case it's already a raw token amount and
+/// `ForeignContract::checked_usd_to_token_amount` is skipped; see
+/// [`ForeignContract::fee_in_token_units`]. `fee_native_token_amount` is a further floor on the
+/// resolved fee, denominated directly in the mint's raw token units; see
+/// [`ForeignContract::fee_native_token_amount`]. Everything is then normalized to Token Bridge's
+/// 8-decimal precision before comparison, since that is the precision the redeeming chain will
+/// actually see. The resulting fee is clamped to `RegisteredToken::max_fee_bps` of the normalized
+/// transfer amount before deduction, so it can't consume a disproportionate share of a small
+/// transfer's value.
+#[allow(clippy::too_many_arguments)]
+pub fn prepare_transfer(
+    config: &SenderConfig,
+    registered_token: &RegisteredToken,
+    fee_in_token_units: bool,
+    fee_native_token_amount: u64,
+    mint: Pubkey,
+    decimals: u8,
+    amount: u64,
+    to_native_token_amount: u64,
+    relayer_fee: u64,
+    recipient_chain: u16,
+    recipient_address: [u8; 32],
+) -> Result<u64> {
+    require!(amount > 0, TokenBridgeRelayerError::ZeroBridgeAmount);
+    validate_evm_recipient(recipient_chain, &recipient_address)?;
+    require!(
+        to_native_token_amount == 0 || registered_token.swap_enabled,
+        TokenBridgeRelayerError::SwapDisabledForToken
+    );
+    require!(
+        to_native_token_amount <= registered_token.max_native_swap_amount,
+        TokenBridgeRelayerError::NativeSwapAmountExceedsMax
+    );
+    require!(
+        to_native_token_amount == 0 || normalize_amount(to_native_token_amount, decimals) > 0,
+        TokenBridgeRelayerError::ToNativeAmountTooSmallForDecimals
+    );
+
+    if config.max_swap_rate_age_slots > 0 {
+        let age = Clock::get()?
+            .slot
+            .saturating_sub(registered_token.last_swap_rate_update);
+        require!(
+            age <= config.max_swap_rate_age_slots,
+            TokenBridgeRelayerError::SwapRateStale
+        );
+    }
+
+    let relayer_fee_tokens = if fee_in_token_units {
+        relayer_fee
+    } else {
+        ForeignContract::checked_usd_to_token_amount(
+            relayer_fee,
+            decimals,
+            registered_token.swap_rate,
+            config.relayer_fee_precision,
+        )?
+    };
+    let relayer_fee_tokens = relayer_fee_tokens.max(fee_native_token_amount);
+
+    let normalized_amount = normalize_amount(amount, decimals);
+    let mut normalized_relayer_fee = normalize_amount(relayer_fee_tokens, decimals);
+    let normalized_to_native_amount = normalize_amount(to_native_token_amount, decimals);
+
+    if registered_token.max_fee_bps > 0 {
+        let fee_cap =
+            (normalized_amount as u128 * registered_token.max_fee_bps as u128 / 10_000) as u64;
+        if normalized_relayer_fee > fee_cap {
+            emit!(MaxFeeCapApplied {
+                mint,
+                requested_fee: normalized_relayer_fee,
+                capped_fee: fee_cap,
+            });
+            normalized_relayer_fee = fee_cap;
+        }
+    }
+
+    let deductions = normalized_relayer_fee
+        .checked_add(normalized_to_native_amount)
+        .ok_or(TokenBridgeRelayerError::InsufficientFunds)?;
+    require!(
+        normalized_amount > deductions,
+        TokenBridgeRelayerError::InsufficientFunds
+    );
+
+    let recipient_amount = normalized_amount - deductions;
+    require!(recipient_amount > 0, TokenBridgeRelayerError::DustTransfer);
+
+    Ok(recipient_amount)
+}
+
+/// Looks up `[SEED_PREFIX_RECIPIENT_BLACKLIST, recipient_address]` among `remaining_accounts`
+/// and returns whether it exists and is marked blocked. The account is optional so that transfers
+/// that don't opt into the blacklist check via `check_blacklist` don't need to include it.
+pub fn recipient_is_blacklisted(
+    program_id: &Pubkey,
+    remaining_accounts: &[AccountInfo],
+    recipient_address: [u8; 32],
+) -> Result<bool> {
+    let (blacklist_pda, _) = Pubkey::find_program_address(
+        &[SEED_PREFIX_RECIPIENT_BLACKLIST, &recipient_address],
+        program_id,
+    );
+
+    let Some(account_info) = remaining_accounts
+        .iter()
+        .find(|account| account.key() == blacklist_pda)
+    else {
+        return Ok(false);
+    };
+
+    if account_info.owner != program_id || account_info.data_is_empty() {
+        return Ok(false);
+    }
+
+    let blacklist_entry = Account::<RecipientBlacklist>::try_from(account_info)?;
+    Ok(blacklist_entry.is_blocked)
+}
+
+/// Looks up `[SEED_PREFIX_CHAIN_VOLUME_LIMIT, chain]` among `remaining_accounts` and, if it
+/// exists and has a nonzero `daily_limit`, records `normalized_amount` against its rolling
+/// window and rejects the transfer if the window's cap is exceeded. The account is optional so
+/// that chains without a configured cap don't need it included.
+pub fn enforce_chain_volume_limit(
+    program_id: &Pubkey,
+    remaining_accounts: &[AccountInfo],
+    chain: u16,
+    current_slot: u64,
+    normalized_amount: u64,
+) -> Result<()> {
+    let (chain_volume_limit_pda, _) = Pubkey::find_program_address(
+        &[SEED_PREFIX_CHAIN_VOLUME_LIMIT, &chain.to_be_bytes()[..]],
+        program_id,
+    );
+
+    let Some(account_info) = remaining_accounts
+        .iter()
+        .find(|account| account.key() == chain_volume_limit_pda)
+    else {
+        return Ok(());
+    };
+
+    if account_info.owner != program_id || account_info.data_is_empty() {
+        return Ok(());
+    }
+
+    let mut chain_volume_limit = Account::<ChainVolumeLimit>::try_from(account_info)?;
+    if chain_volume_limit.daily_limit == 0 {
+        return Ok(());
+    }
+
+    let volume_this_window = chain_volume_limit.record(current_slot, normalized_amount);
+    require!(
+        volume_this_window <= chain_volume_limit.daily_limit,
+        TokenBridgeRelayerError::ChainVolumeLimitExceeded
+    );
+
+    chain_volume_limit.exit(program_id)
+}
+
+/// Looks up `[SEED_PREFIX_SUPPORTED_CHAINS_CONFIG]` among `remaining_accounts` and, if it exists
+/// and `enforce_allowlist` is set, requires `[SEED_PREFIX_SUPPORTED_CHAIN, chain]` to also be
+/// present. Both accounts are optional so transfers to a chain that hasn't opted into allowlist
+/// enforcement don't need to include either.
+pub fn enforce_supported_chain_allowlist(
+    program_id: &Pubkey,
+    remaining_accounts: &[AccountInfo],
+    chain: u16,
+) -> Result<()> {
+    let (config_pda, _) =
+        Pubkey::find_program_address(&[SEED_PREFIX_SUPPORTED_CHAINS_CONFIG], program_id);
+
+    let Some(config_info) = remaining_accounts
+        .iter()
+        .find(|account| account.key() == config_pda)
+    else {
+        return Ok(());
+    };
+
+    if config_info.owner != program_id || config_info.data_is_empty() {
+        return Ok(());
+    }
+
+    let config = Account::<SupportedChainsConfig>::try_from(config_info)?;
+    if !config.enforce_allowlist {
+        return Ok(());
+    }
+
+    let (supported_chain_pda, _) = Pubkey::find_program_address(
+        &[SEED_PREFIX_SUPPORTED_CHAIN, &chain.to_be_bytes()],
+        program_id,
+    );
+
+    let Some(supported_chain_info) = remaining_accounts
+        .iter()
+        .find(|account| account.key() == supported_chain_pda)
+    else {
+        return Err(TokenBridgeRelayerError::ChainNotSupported.into());
+    };
+
+    if supported_chain_info.owner != program_id || supported_chain_info.data_is_empty() {
+        return Err(TokenBridgeRelayerError::ChainNotSupported.into());
+    }
+
+    let supported_chain = Account::<SupportedChain>::try_from(supported_chain_info)?;
+    require!(
+        supported_chain.chain == chain,
+        TokenBridgeRelayerError::ChainNotSupported
+    );
+
+    Ok(())
+}
+
+/// Looks up `[SEED_PREFIX_EPOCH_FEE_SCHEDULE, chain]` among `remaining_accounts` and, if it
+/// exists, returns its `effective_fee` for `current_slot` (`promo_fee` inside the promotional
+/// window, `base_fee` otherwise) in place of `foreign_contract.fee`. The account is optional so
+/// that chains without a configured schedule fall back to the foreign contract's flat fee.
+pub fn resolve_relayer_fee(
+    program_id: &Pubkey,
+    remaining_accounts: &[AccountInfo],
+    chain: u16,
+    current_slot: u64,
+    foreign_contract_fee: u64,
+) -> Result<u64> {
+    let (epoch_fee_schedule_pda, _) = Pubkey::find_program_address(
+        &[SEED_PREFIX_EPOCH_FEE_SCHEDULE, &chain.to_be_bytes()[..]],
+        program_id,
+    );
+
+    let Some(account_info) = remaining_accounts
+        .iter()
+        .find(|account| account.key() == epoch_fee_schedule_pda)
+    else {
+        return Ok(foreign_contract_fee);
+    };
+
+    if account_info.owner != program_id || account_info.data_is_empty() {
+        return Ok(foreign_contract_fee);
+    }
+
+    let epoch_fee_schedule = Account::<EpochFeeSchedule>::try_from(account_info)?;
+    Ok(epoch_fee_schedule.effective_fee(current_slot))
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn transfer_native_tokens_with_relay(
+    ctx: Context<TransferNativeTokensWithRelay>,
+    amount: u64,
+    to_native_token_amount: u64,
+    recipient_chain: u16,
+    recipient_address: [u8; 32],
+    _batch_id: u32,
+    check_blacklist: bool,
+    // Reserved for encoding into the transfer-with-payload message once the Token Bridge CPI is
+    // implemented; unused for now, matching `_batch_id`.
+    _memo: [u8; 32],
+) -> Result<()> {
+    require!(
+        !ctx.accounts.config.paused,
+        TokenBridgeRelayerError::OutboundTransfersPaused
+    );
+    require!(
+        recipient_address != [0u8; 32],
+        TokenBridgeRelayerError::InvalidRecipient
+    );
+    require!(
+        ctx.accounts.foreign_contract.is_active,
+        TokenBridgeRelayerError::ForeignContractInactive
+    );
+
+    let rent_minimum = Rent::get()?.minimum_balance(0);
+    require!(
+        ctx.accounts.payer.lamports()
+            >= ctx
+                .accounts
+                .config
+                .wormhole_message_fee
+                .saturating_add(rent_minimum)
+                .saturating_add(ESTIMATED_TRANSACTION_FEE_LAMPORTS),
+        TokenBridgeRelayerError::InsufficientLamportsForWormholeFee
+    );
+
+    enforce_supported_chain_allowlist(ctx.program_id, ctx.remaining_accounts, recipient_chain)?;
+
+    if check_blacklist {
+        require!(
+            !recipient_is_blacklisted(ctx.program_id, ctx.remaining_accounts, recipient_address)?,
+            TokenBridgeRelayerError::RecipientBlacklisted
+        );
+    }
+
+    let decimals = ctx.accounts.registered_token.decimals;
+    let relayer_fee = resolve_relayer_fee(
+        ctx.program_id,
+        ctx.remaining_accounts,
+        recipient_chain,
+        Clock::get()?.slot,
+        ctx.accounts.foreign_contract.fee,
+    )?;
+    prepare_transfer(
+        &ctx.accounts.config,
+        &ctx.accounts.registered_token,
+        ctx.accounts.foreign_contract.fee_in_token_units,
+        ctx.accounts.foreign_contract.fee_native_token_amount,
+        ctx.accounts.mint.key(),
+        decimals,
+        amount,
+        to_native_token_amount,
+        relayer_fee,
+        recipient_chain,
+        recipient_address,
+    )?;
+
+    let truncated_amount = crate::processor::truncate_amount(amount, decimals);
+    require!(
+        truncated_amount > 0,
+        TokenBridgeRelayerError::ZeroBridgeAmount
+    );
+
+    let tripped = ctx.accounts.circuit_breaker.record(
+        Clock::get()?.slot,
+        normalize_amount(truncated_amount, decimals),
+    );
+    require!(!tripped, TokenBridgeRelayerError::CircuitBreakerTripped);
+
+    if ctx.accounts.registered_token.max_transfer_amount > 0 {
+        require!(
+            normalize_amount(truncated_amount, decimals)
+                <= ctx.accounts.registered_token.max_transfer_amount,
+            TokenBridgeRelayerError::TransferExceedsMaximum
+        );
+    }
+
+    enforce_chain_volume_limit(
+        ctx.program_id,
+        ctx.remaining_accounts,
+        recipient_chain,
+        Clock::get()?.slot,
+        normalize_amount(truncated_amount, decimals),
+    )?;
+
+    if ctx.accounts.config.large_transfer_threshold > 0 {
+        let normalized_amount = normalize_amount(truncated_amount, decimals);
+        if normalized_amount > ctx.accounts.config.large_transfer_threshold {
+            emit!(LargeTransferWarning {
+                payer: ctx.accounts.payer.key(),
+                mint: ctx.accounts.mint.key(),
+                amount: normalized_amount,
+                recipient_chain,
+            });
+        }
+    }
+
+    if ctx.accounts.config.rate_limit_window_slots > 0 {
+        let normalized_amount = normalize_amount(truncated_amount, decimals);
+        let amount_in_window = ctx.accounts.wallet_rate_limit.record(
+            ctx.accounts.payer.key(),
+            Clock::get()?.slot,
+            ctx.accounts.config.rate_limit_window_slots,
+            normalized_amount,
+        );
+        require!(
+            amount_in_window <= ctx.accounts.config.rate_limit_max_amount,
+            TokenBridgeRelayerError::WalletRateLimitExceeded
+        );
+    }
+
+    token_interface::transfer_checked(
+        CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            TransferChecked {
+                from: ctx.accounts.from_token_account.to_account_info(),
+                mint: ctx.accounts.mint.to_account_info(),
+                to: ctx.accounts.tmp_token_account.to_account_info(),
+                authority: ctx.accounts.payer.to_account_info(),
+            },
+        ),
+        amount,
+        decimals,
+    )?;
+
+    let residual = amount.saturating_sub(truncated_amount);
+    if residual > 0 {
+        let config_seeds: &[&[u8]] = &[SEED_PREFIX_SENDER, &[ctx.accounts.config.bump]];
+        token_interface::transfer_checked(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                TransferChecked {
+                    from: ctx.accounts.tmp_token_account.to_account_info(),
+                    mint: ctx.accounts.mint.to_account_info(),
+                    to: ctx.accounts.from_token_account.to_account_info(),
+                    authority: ctx.accounts.config.to_account_info(),
+                },
+                &[config_seeds],
+            ),
+            residual,
+            decimals,
+        )?;
+
+        emit!(TruncationResidualRefunded { residual });
+    }
+
+    ctx.accounts
+        .registered_token
+        .record_volume_in(truncated_amount);
+
+    ctx.accounts.program_stats.record_transfer_out();
+    ctx.accounts.chain_stats.chain = recipient_chain;
+    ctx.accounts.chain_stats.record_transfer_out();
+    ctx.accounts
+        .payer_transfer_history
+        .record_outbound(truncated_amount, Clock::get()?.slot);
+
+    ctx.accounts.signer_sequence.value = ctx
+        .accounts
+        .signer_sequence
+        .value
+        .checked_add(1)
+        .ok_or(TokenBridgeRelayerError::InsufficientFunds)?;
+
+    // Token Bridge `transfer_tokens_with_payload` CPI happens here in the deployed program.
+
+    ctx.accounts.signer_sequence.last_committed_sequence = ctx.accounts.signer_sequence.value;
+
+    // Logged so a relayer can compare actual compute unit consumption against
+    // `constants::ComputeUnitEstimates::TRANSFER_NATIVE` and size its
+    // `ComputeBudgetInstruction::set_compute_unit_limit` accordingly.
+    solana_program::log::sol_log_compute_units();
+
+    Ok(())
+}
@@ -0,0 +1,310 @@
+use crate::{
+    error::TokenBridgeRelayerError,
+    events::{LargeTransferWarning, TruncationResidualRefunded},
+    processor::{
+        enforce_chain_volume_limit, enforce_supported_chain_allowlist, normalize_amount,
+        prepare_transfer, recipient_is_blacklisted, resolve_relayer_fee, truncate_amount,
+    },
+    state::{
+        ChainStats, CircuitBreaker, ForeignContract, PayerTransferHistory, ProgramStats,
+        RegisteredToken, SenderConfig, SignerSequence, WalletRateLimit,
+    },
+    ESTIMATED_TRANSACTION_FEE_LAMPORTS, SEED_PREFIX_CHAIN_STATS, SEED_PREFIX_CIRCUIT_BREAKER,
+    SEED_PREFIX_FOREIGN_CONTRACT, SEED_PREFIX_PAYER_HISTORY, SEED_PREFIX_PROGRAM_STATS,
+    SEED_PREFIX_REGISTERED_TOKEN, SEED_PREFIX_SENDER, SEED_PREFIX_SIGNER_SEQUENCE, SEED_PREFIX_TMP,
+    SEED_PREFIX_WALLET_RATE_LIMIT,
+};
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{self, Mint, TokenAccount, TokenInterface, TransferChecked};
+
+#[derive(Accounts)]
+#[instruction(amount: u64, to_native_token_amount: u64, recipient_chain: u16)]
+pub struct TransferWrappedTokensWithRelay<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    #[account(
+        seeds = [SEED_PREFIX_SENDER],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, SenderConfig>,
+
+    #[account(
+        seeds = [SEED_PREFIX_FOREIGN_CONTRACT, &recipient_chain.to_be_bytes()[..]],
+        bump,
+    )]
+    pub foreign_contract: Account<'info, ForeignContract>,
+
+    /// Wormhole-wrapped mint for the token being sent.
+    pub token_bridge_wrapped_mint: InterfaceAccount<'info, Mint>,
+
+    #[account(mut)]
+    pub from_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    /// `constraint` is redundant with `init` creating this account fresh on every call, but
+    /// guards against a PDA-collision exploit slipping a pre-funded account past `init` (e.g. an
+    /// account already owned by the token program with a nonzero balance sitting at this address
+    /// for some reason `init` doesn't itself reject).
+    #[account(
+        init,
+        payer = payer,
+        seeds = [SEED_PREFIX_TMP, token_bridge_wrapped_mint.key().as_ref()],
+        bump,
+        token::mint = token_bridge_wrapped_mint,
+        token::authority = config,
+        constraint = tmp_token_account.amount == 0 @ TokenBridgeRelayerError::TmpAccountNotEmpty,
+    )]
+    pub tmp_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        seeds = [SEED_PREFIX_REGISTERED_TOKEN, token_bridge_wrapped_mint.key().as_ref()],
+        bump,
+    )]
+    pub registered_token: Account<'info, RegisteredToken>,
+
+    #[account(
+        init_if_needed,
+        payer = payer,
+        seeds = [SEED_PREFIX_SIGNER_SEQUENCE, payer.key().as_ref()],
+        bump,
+        space = SignerSequence::MAXIMUM_SIZE,
+    )]
+    pub signer_sequence: Account<'info, SignerSequence>,
+
+    #[account(
+        init_if_needed,
+        payer = payer,
+        seeds = [SEED_PREFIX_PROGRAM_STATS],
+        bump,
+        space = ProgramStats::MAXIMUM_SIZE,
+    )]
+    pub program_stats: Account<'info, ProgramStats>,
+
+    #[account(
+        init_if_needed,
+        payer = payer,
+        seeds = [SEED_PREFIX_CHAIN_STATS, &recipient_chain.to_le_bytes()[..]],
+        bump,
+        space = ChainStats::MAXIMUM_SIZE,
+    )]
+    pub chain_stats: Account<'info, ChainStats>,
+
+    #[account(
+        init_if_needed,
+        payer = payer,
+        seeds = [SEED_PREFIX_WALLET_RATE_LIMIT, payer.key().as_ref()],
+        bump,
+        space = WalletRateLimit::MAXIMUM_SIZE,
+    )]
+    pub wallet_rate_limit: Account<'info, WalletRateLimit>,
+
+    #[account(
+        init_if_needed,
+        payer = payer,
+        seeds = [SEED_PREFIX_PAYER_HISTORY, payer.key().as_ref()],
+        bump,
+        space = PayerTransferHistory::MAXIMUM_SIZE,
+    )]
+    pub payer_transfer_history: Account<'info, PayerTransferHistory>,
+
+    #[account(
+        init_if_needed,
+        payer = payer,
+        seeds = [SEED_PREFIX_CIRCUIT_BREAKER],
+        bump,
+        space = CircuitBreaker::MAXIMUM_SIZE,
+    )]
+    pub circuit_breaker: Account<'info, CircuitBreaker>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn transfer_wrapped_tokens_with_relay(
+    ctx: Context<TransferWrappedTokensWithRelay>,
+    amount: u64,
+    to_native_token_amount: u64,
+    recipient_chain: u16,
+    recipient_address: [u8; 32],
+    _batch_id: u32,
+    check_blacklist: bool,
+    // Reserved for encoding into the transfer-with-payload message once the Token Bridge CPI is
+    // implemented; unused for now, matching `_batch_id`.
+    _memo: [u8; 32],
+) -> Result<()> {
+    require!(
+        !ctx.accounts.config.paused,
+        TokenBridgeRelayerError::OutboundTransfersPaused
+    );
+    require!(
+        recipient_address != [0u8; 32],
+        TokenBridgeRelayerError::InvalidRecipient
+    );
+    require!(
+        ctx.accounts.foreign_contract.is_active,
+        TokenBridgeRelayerError::ForeignContractInactive
+    );
+
+    let rent_minimum = Rent::get()?.minimum_balance(0);
+    require!(
+        ctx.accounts.payer.lamports()
+            >= ctx
+                .accounts
+                .config
+                .wormhole_message_fee
+                .saturating_add(rent_minimum)
+                .saturating_add(ESTIMATED_TRANSACTION_FEE_LAMPORTS),
+        TokenBridgeRelayerError::InsufficientLamportsForWormholeFee
+    );
+
+    enforce_supported_chain_allowlist(ctx.program_id, ctx.remaining_accounts, recipient_chain)?;
+
+    if check_blacklist {
+        require!(
+            !recipient_is_blacklisted(ctx.program_id, ctx.remaining_accounts, recipient_address)?,
+            TokenBridgeRelayerError::RecipientBlacklisted
+        );
+    }
+
+    require!(amount > 0, TokenBridgeRelayerError::ZeroBridgeAmount);
+
+    let relayer_fee = resolve_relayer_fee(
+        ctx.program_id,
+        ctx.remaining_accounts,
+        recipient_chain,
+        Clock::get()?.slot,
+        ctx.accounts.foreign_contract.fee,
+    )?;
+    prepare_transfer(
+        &ctx.accounts.config,
+        &ctx.accounts.registered_token,
+        ctx.accounts.foreign_contract.fee_in_token_units,
+        ctx.accounts.foreign_contract.fee_native_token_amount,
+        ctx.accounts.token_bridge_wrapped_mint.key(),
+        ctx.accounts.registered_token.decimals,
+        amount,
+        to_native_token_amount,
+        relayer_fee,
+        recipient_chain,
+        recipient_address,
+    )?;
+
+    let decimals = ctx.accounts.registered_token.decimals;
+    // Mirrors `transfer_native_tokens_with_relay`: reject an `amount` that rounds down to zero
+    // once normalized to Token Bridge's 8-decimal precision, even though the earlier
+    // `amount > 0` check above already passed.
+    let truncated_amount = truncate_amount(amount, decimals);
+    require!(
+        truncated_amount > 0,
+        TokenBridgeRelayerError::ZeroBridgeAmount
+    );
+
+    let tripped = ctx.accounts.circuit_breaker.record(
+        Clock::get()?.slot,
+        normalize_amount(truncated_amount, decimals),
+    );
+    require!(!tripped, TokenBridgeRelayerError::CircuitBreakerTripped);
+
+    if ctx.accounts.registered_token.max_transfer_amount > 0 {
+        require!(
+            normalize_amount(truncated_amount, decimals)
+                <= ctx.accounts.registered_token.max_transfer_amount,
+            TokenBridgeRelayerError::TransferExceedsMaximum
+        );
+    }
+
+    enforce_chain_volume_limit(
+        ctx.program_id,
+        ctx.remaining_accounts,
+        recipient_chain,
+        Clock::get()?.slot,
+        normalize_amount(truncated_amount, decimals),
+    )?;
+
+    if ctx.accounts.config.large_transfer_threshold > 0 {
+        let normalized_amount = normalize_amount(truncated_amount, decimals);
+        if normalized_amount > ctx.accounts.config.large_transfer_threshold {
+            emit!(LargeTransferWarning {
+                payer: ctx.accounts.payer.key(),
+                mint: ctx.accounts.token_bridge_wrapped_mint.key(),
+                amount: normalized_amount,
+                recipient_chain,
+            });
+        }
+    }
+
+    if ctx.accounts.config.rate_limit_window_slots > 0 {
+        let normalized_amount = normalize_amount(truncated_amount, decimals);
+        let amount_in_window = ctx.accounts.wallet_rate_limit.record(
+            ctx.accounts.payer.key(),
+            Clock::get()?.slot,
+            ctx.accounts.config.rate_limit_window_slots,
+            normalized_amount,
+        );
+        require!(
+            amount_in_window <= ctx.accounts.config.rate_limit_max_amount,
+            TokenBridgeRelayerError::WalletRateLimitExceeded
+        );
+    }
+
+    token_interface::transfer_checked(
+        CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            TransferChecked {
+                from: ctx.accounts.from_token_account.to_account_info(),
+                mint: ctx.accounts.token_bridge_wrapped_mint.to_account_info(),
+                to: ctx.accounts.tmp_token_account.to_account_info(),
+                authority: ctx.accounts.payer.to_account_info(),
+            },
+        ),
+        amount,
+        decimals,
+    )?;
+
+    let residual = amount.saturating_sub(truncated_amount);
+    if residual > 0 {
+        let config_seeds: &[&[u8]] = &[SEED_PREFIX_SENDER, &[ctx.accounts.config.bump]];
+        token_interface::transfer_checked(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                TransferChecked {
+                    from: ctx.accounts.tmp_token_account.to_account_info(),
+                    mint: ctx.accounts.token_bridge_wrapped_mint.to_account_info(),
+                    to: ctx.accounts.from_token_account.to_account_info(),
+                    authority: ctx.accounts.config.to_account_info(),
+                },
+                &[config_seeds],
+            ),
+            residual,
+            decimals,
+        )?;
+
+        emit!(TruncationResidualRefunded { residual });
+    }
+
+    ctx.accounts
+        .registered_token
+        .record_volume_in(truncated_amount);
+
+    ctx.accounts.program_stats.record_transfer_out();
+    ctx.accounts.chain_stats.chain = recipient_chain;
+    ctx.accounts.chain_stats.record_transfer_out();
+    ctx.accounts
+        .payer_transfer_history
+        .record_outbound(truncated_amount, Clock::get()?.slot);
+
+    ctx.accounts.signer_sequence.value = ctx
+        .accounts
+        .signer_sequence
+        .value
+        .checked_add(1)
+        .ok_or(TokenBridgeRelayerError::InsufficientFunds)?;
+
+    // Token Bridge `transfer_wrapped_with_payload` CPI happens here in the deployed program.
+
+    ctx.accounts.signer_sequence.last_committed_sequence = ctx.accounts.signer_sequence.value;
+
+    Ok(())
+}
@@ -0,0 +1,13 @@
+use anchor_lang::prelude::*;
+
+/// Marker PDA, seeded by `[SEED_PREFIX_ALLOWED_CHAIN, &chain.to_be_bytes()]`, whose existence
+/// means `chain` may redeem transfers while [`super::SourceChainAllowlist`] is enabled.
+#[account]
+#[derive(Default)]
+pub struct AllowedSourceChain {
+    pub chain: u16,
+}
+
+impl AllowedSourceChain {
+    pub const MAXIMUM_SIZE: usize = 8 + 2;
+}
@@ -0,0 +1,58 @@
+use anchor_lang::prelude::*;
+
+/// Governance action recorded by [`AdminAuditLog`]. Not every action that mutates a `u64` config
+/// value naturally involves one (e.g. changing a `Pubkey`), so `old_value`/`new_value` are left
+/// as `0` on entries where they don't apply.
+pub const AUDIT_ACTION_UPDATE_RELAYER_FEE: u8 = 1;
+pub const AUDIT_ACTION_UPDATE_SWAP_RATE: u8 = 2;
+pub const AUDIT_ACTION_UPDATE_MAX_NATIVE_SWAP_AMOUNT: u8 = 3;
+pub const AUDIT_ACTION_UPDATE_FEE_RECIPIENT: u8 = 4;
+pub const AUDIT_ACTION_CONFIRM_OWNERSHIP_TRANSFER: u8 = 5;
+pub const AUDIT_ACTION_UPDATE_FEE_NATIVE_TOKEN_AMOUNT: u8 = 6;
+
+/// Singleton PDA seeded by `[SEED_PREFIX_AUDIT_LOG_CONFIG]` that tracks how many
+/// `AdminAuditLog` entries have been appended, so each new entry gets a unique, sequential seed.
+#[account]
+#[derive(Default)]
+pub struct AuditLogConfig {
+    pub counter: u64,
+}
+
+impl AuditLogConfig {
+    pub const MAXIMUM_SIZE: usize = 8 + 8;
+}
+
+/// Append-only record of a single governance action, seeded by
+/// `[SEED_PREFIX_AUDIT_LOG, &counter.to_be_bytes()]` where `counter` is the value of
+/// `AuditLogConfig::counter` at the time the entry was created.
+#[account]
+#[derive(Default)]
+pub struct AdminAuditLog {
+    pub action_type: u8,
+    pub actor: Pubkey,
+    pub target: Option<Pubkey>,
+    pub old_value: u64,
+    pub new_value: u64,
+    pub slot: u64,
+}
+
+impl AdminAuditLog {
+    pub const MAXIMUM_SIZE: usize = 8 + 1 + 32 + (1 + 32) + 8 + 8 + 8;
+
+    pub fn record(
+        &mut self,
+        action_type: u8,
+        actor: Pubkey,
+        target: Option<Pubkey>,
+        old_value: u64,
+        new_value: u64,
+    ) -> Result<()> {
+        self.action_type = action_type;
+        self.actor = actor;
+        self.target = target;
+        self.old_value = old_value;
+        self.new_value = new_value;
+        self.slot = Clock::get()?.slot;
+        Ok(())
+    }
+}
@@ -0,0 +1,22 @@
+use anchor_lang::prelude::*;
+
+/// Per-chain transfer counters, seeded by `[SEED_PREFIX_CHAIN_STATS, chain.to_le_bytes()]`.
+#[account]
+#[derive(Default)]
+pub struct ChainStats {
+    pub chain: u16,
+    pub transfers_in: u64,
+    pub transfers_out: u64,
+}
+
+impl ChainStats {
+    pub const MAXIMUM_SIZE: usize = 8 + 2 + 8 + 8;
+
+    pub fn record_transfer_in(&mut self) {
+        self.transfers_in = self.transfers_in.saturating_add(1);
+    }
+
+    pub fn record_transfer_out(&mut self) {
+        self.transfers_out = self.transfers_out.saturating_add(1);
+    }
+}
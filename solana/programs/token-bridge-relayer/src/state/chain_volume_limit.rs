@@ -0,0 +1,36 @@
+use anchor_lang::prelude::*;
+
+/// Rolling-window outbound volume cap for a single chain, seeded by
+/// `[SEED_PREFIX_CHAIN_VOLUME_LIMIT, chain.to_be_bytes()]`. Acts as a circuit breaker: once
+/// `volume_this_window` would exceed `daily_limit`, further outbound transfers to that chain
+/// are rejected until the window rolls over.
+#[account]
+#[derive(Default)]
+pub struct ChainVolumeLimit {
+    pub chain: u16,
+
+    /// Maximum normalized (8-decimal) amount that may be transferred out to this chain within
+    /// a window. `0` disables the check.
+    pub daily_limit: u64,
+
+    pub window_start_slot: u64,
+    pub volume_this_window: u64,
+
+    /// Length, in slots, of the rolling window `daily_limit` is enforced over.
+    pub slots_per_window: u64,
+}
+
+impl ChainVolumeLimit {
+    pub const MAXIMUM_SIZE: usize = 8 + 2 + 8 + 8 + 8 + 8;
+
+    /// Records `normalized_amount` against the window starting at `current_slot`, resetting
+    /// the window first if it has expired. Returns the new cumulative volume in the window.
+    pub fn record(&mut self, current_slot: u64, normalized_amount: u64) -> u64 {
+        if current_slot.saturating_sub(self.window_start_slot) >= self.slots_per_window {
+            self.window_start_slot = current_slot;
+            self.volume_this_window = 0;
+        }
+        self.volume_this_window = self.volume_this_window.saturating_add(normalized_amount);
+        self.volume_this_window
+    }
+}
@@ -0,0 +1,122 @@
+use anchor_lang::prelude::*;
+
+/// Singleton PDA, seeded by `[SEED_PREFIX_CIRCUIT_BREAKER]`, that halts all outbound transfers
+/// once cumulative volume within a rolling window exceeds `max_volume_per_window`. Unlike
+/// `ChainVolumeLimit`, which only caps a single chain and resumes automatically once its window
+/// rolls over, tripping this breaker latches `tripped = true` across every chain until an owner
+/// explicitly calls `reset_circuit_breaker` — the assumption being that a spike large enough to
+/// trip a program-wide breaker warrants a human looking at it before transfers resume.
+#[account]
+#[derive(Default)]
+pub struct CircuitBreaker {
+    /// Whether the volume check is enforced at all. `false` disables it entirely, including
+    /// tripping; `record` still tracks volume so historical data isn't lost while disabled.
+    pub enabled: bool,
+
+    /// Length, in slots, of the rolling window `max_volume_per_window` is enforced over.
+    pub window_slots: u64,
+
+    /// Maximum normalized (8-decimal) outbound volume allowed within `window_slots`. `0`
+    /// disables the check even when `enabled` is true.
+    pub max_volume_per_window: u64,
+
+    pub volume_this_window: u64,
+    pub window_start_slot: u64,
+
+    /// Once set, every outbound transfer fails until `reset_circuit_breaker` clears it.
+    pub tripped: bool,
+}
+
+impl CircuitBreaker {
+    pub const MAXIMUM_SIZE: usize = 8 + 1 + 8 + 8 + 8 + 8 + 1;
+
+    /// Resets the window if it has expired, then adds `normalized_amount` to
+    /// `volume_this_window` and trips the breaker if that pushes it past
+    /// `max_volume_per_window`. Returns whether the breaker is tripped (either already, or as a
+    /// result of this call) so the caller can reject the transfer.
+    pub fn record(&mut self, current_slot: u64, normalized_amount: u64) -> bool {
+        if self.tripped {
+            return true;
+        }
+
+        if current_slot.saturating_sub(self.window_start_slot) >= self.window_slots {
+            self.window_start_slot = current_slot;
+            self.volume_this_window = 0;
+        }
+        self.volume_this_window = self.volume_this_window.saturating_add(normalized_amount);
+
+        if self.enabled
+            && self.max_volume_per_window > 0
+            && self.volume_this_window > self.max_volume_per_window
+        {
+            self.tripped = true;
+        }
+
+        self.tripped
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn breaker(max_volume_per_window: u64, window_slots: u64) -> CircuitBreaker {
+        CircuitBreaker {
+            enabled: true,
+            window_slots,
+            max_volume_per_window,
+            volume_this_window: 0,
+            window_start_slot: 0,
+            tripped: false,
+        }
+    }
+
+    #[test]
+    fn record_accumulates_within_window_without_tripping() {
+        let mut b = breaker(1_000, 100);
+        assert!(!b.record(0, 400));
+        assert!(!b.record(50, 400));
+        assert_eq!(b.volume_this_window, 800);
+    }
+
+    #[test]
+    fn record_trips_once_window_volume_exceeds_max() {
+        let mut b = breaker(1_000, 100);
+        assert!(!b.record(0, 900));
+        assert!(b.record(10, 200));
+        assert!(b.tripped);
+    }
+
+    #[test]
+    fn record_stays_tripped_and_ignores_further_volume() {
+        let mut b = breaker(1_000, 100);
+        assert!(b.record(0, 1_001));
+        assert!(b.record(1, 0));
+        assert_eq!(b.volume_this_window, 1_001);
+    }
+
+    #[test]
+    fn record_resets_volume_once_window_elapses() {
+        let mut b = breaker(1_000, 100);
+        assert!(!b.record(0, 900));
+        // Window has fully rolled over, so this should start a fresh window instead of tripping.
+        assert!(!b.record(100, 900));
+        assert_eq!(b.volume_this_window, 900);
+        assert_eq!(b.window_start_slot, 100);
+    }
+
+    #[test]
+    fn record_tracks_volume_but_never_trips_when_disabled() {
+        let mut b = breaker(1_000, 100);
+        b.enabled = false;
+        assert!(!b.record(0, 5_000));
+        assert_eq!(b.volume_this_window, 5_000);
+        assert!(!b.tripped);
+    }
+
+    #[test]
+    fn record_never_trips_when_max_volume_per_window_is_zero() {
+        let mut b = breaker(0, 100);
+        assert!(!b.record(0, u64::MAX));
+    }
+}
@@ -0,0 +1,30 @@
+use anchor_lang::prelude::*;
+
+/// Optional promotional fee window for a single chain, seeded by
+/// `[SEED_PREFIX_EPOCH_FEE_SCHEDULE, chain.to_be_bytes()]`. While `Clock::slot` is within
+/// `[promo_start_slot, promo_end_slot)`, transfers to this chain use `promo_fee` instead of
+/// `ForeignContract::fee`, letting operators run a time-boxed promotion without touching the
+/// chain's normal fee.
+#[account]
+#[derive(Default)]
+pub struct EpochFeeSchedule {
+    pub chain: u16,
+    pub base_fee: u64,
+    pub promo_fee: u64,
+    pub promo_start_slot: u64,
+    pub promo_end_slot: u64,
+}
+
+impl EpochFeeSchedule {
+    pub const MAXIMUM_SIZE: usize = 8 + 2 + 8 + 8 + 8 + 8;
+
+    /// Returns `promo_fee` if `current_slot` falls within the promotional window, otherwise
+    /// `base_fee`.
+    pub fn effective_fee(&self, current_slot: u64) -> u64 {
+        if current_slot >= self.promo_start_slot && current_slot < self.promo_end_slot {
+            self.promo_fee
+        } else {
+            self.base_fee
+        }
+    }
+}
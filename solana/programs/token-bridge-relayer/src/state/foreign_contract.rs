@@ -0,0 +1,95 @@
+use crate::{
+    error::TokenBridgeRelayerError, utils::valid_foreign_contract_address, SWAP_RATE_PRECISION,
+};
+use anchor_lang::prelude::*;
+
+/// Registered relayer contract on a foreign chain, seeded by
+/// `[SEED_PREFIX_FOREIGN_CONTRACT, chain.to_be_bytes()]`.
+#[account]
+#[derive(Default)]
+pub struct ForeignContract {
+    /// Wormhole chain ID.
+    pub chain: u16,
+
+    /// Emitter address on the foreign chain, left-zero-padded to 32 bytes.
+    pub address: [u8; 32],
+
+    /// Token Bridge program's foreign endpoint address, used to verify inbound transfers
+    /// actually originated from the Token Bridge on `chain`.
+    pub token_bridge_foreign_endpoint: [u8; 32],
+
+    /// Relayer fee, denominated in USD, scaled by `SenderConfig::relayer_fee_precision`.
+    pub fee: u64,
+
+    /// Lower bound `fee` may be set to via `update_relayer_fee`. Zero means unbounded.
+    pub min_fee: u64,
+
+    /// Upper bound `fee` may be set to via `update_relayer_fee`. Zero means unbounded.
+    pub max_fee: u64,
+
+    /// Whether outbound transfers to this chain are currently allowed.
+    pub is_active: bool,
+
+    /// When `true`, `fee` is a raw token amount rather than a USD amount, and `prepare_transfer`
+    /// uses it directly as the relayer fee instead of running it through
+    /// [`Self::checked_usd_to_token_amount`]. Meant for stable-value tokens (e.g. USDC), where
+    /// the USD/token conversion is redundant overhead since 1 token already tracks $1.
+    pub fee_in_token_units: bool,
+
+    /// Floor on the relayer fee, denominated directly in the mint's raw token units rather than
+    /// USD. Meant for chains where the relayer pays gas in the destination chain's native asset
+    /// (e.g. ETH) and wants to recoup a fixed token-denominated gas cost without needing a
+    /// USD/gas-token exchange rate. `0` means unset. When nonzero, `prepare_transfer` takes the
+    /// larger of this and whatever `fee`/`fee_in_token_units` would otherwise resolve to, so
+    /// setting both never lets the USD-denominated fee undercut the known gas cost.
+    pub fee_native_token_amount: u64,
+}
+
+impl ForeignContract {
+    pub const MAXIMUM_SIZE: usize = 8 + 2 + 32 + 32 + 8 + 8 + 8 + 1 + 1 + 8;
+
+    /// Validates a foreign emitter address: nonzero and not a reserved program address. See
+    /// [`valid_foreign_contract_address`].
+    pub fn is_valid_address(address: &[u8; 32]) -> bool {
+        valid_foreign_contract_address(address)
+    }
+
+    /// Converts `self.fee` (USD, scaled by `relayer_fee_precision`) into the mint's raw token
+    /// units, using `swap_rate` (USD price of one whole token, scaled by `SWAP_RATE_PRECISION`).
+    pub fn checked_token_fee(
+        &self,
+        decimals: u8,
+        swap_rate: u64,
+        relayer_fee_precision: u32,
+    ) -> Result<u64> {
+        Self::checked_usd_to_token_amount(self.fee, decimals, swap_rate, relayer_fee_precision)
+    }
+
+    /// Converts a USD amount (scaled by `relayer_fee_precision`) into the mint's raw token units,
+    /// using `swap_rate` (USD price of one whole token, scaled by `SWAP_RATE_PRECISION`). Shared
+    /// by [`Self::checked_token_fee`] and `processor::prepare_transfer`'s
+    /// `fee_in_token_units == false` path, which converts a resolved relayer fee that may have
+    /// come from an `EpochFeeSchedule` override rather than `self.fee`.
+    pub fn checked_usd_to_token_amount(
+        usd_amount: u64,
+        decimals: u8,
+        swap_rate: u64,
+        relayer_fee_precision: u32,
+    ) -> Result<u64> {
+        require!(
+            swap_rate > 0,
+            TokenBridgeRelayerError::NonexistentRelayerFee
+        );
+
+        let numerator = (usd_amount as u128)
+            .checked_mul(10u128.pow(decimals as u32))
+            .and_then(|v| v.checked_mul(SWAP_RATE_PRECISION as u128))
+            .ok_or(TokenBridgeRelayerError::InsufficientFunds)?;
+        let denominator = (swap_rate as u128)
+            .checked_mul(relayer_fee_precision as u128)
+            .ok_or(TokenBridgeRelayerError::InsufficientFunds)?;
+
+        u64::try_from(numerator / denominator)
+            .map_err(|_| TokenBridgeRelayerError::InsufficientFunds.into())
+    }
+}
@@ -0,0 +1,16 @@
+use anchor_lang::prelude::*;
+
+/// Replay-protection record for a governance action, seeded by
+/// `[SEED_PREFIX_GOVERNANCE_CLAIM, &vaa_hash]`, mirroring `TransferReceipt`'s claim pattern:
+/// `execute_governance_action` can't apply the same VAA hash twice because the second attempt
+/// fails to `init` this account.
+#[account]
+#[derive(Default)]
+pub struct GovernanceClaim {
+    pub executed_at_slot: u64,
+    pub action_type: u8,
+}
+
+impl GovernanceClaim {
+    pub const MAXIMUM_SIZE: usize = 8 + 8 + 1;
+}
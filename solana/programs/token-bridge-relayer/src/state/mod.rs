@@ -0,0 +1,89 @@
+mod allowed_source_chain;
+pub use allowed_source_chain::*;
+
+mod audit_log;
+pub use audit_log::*;
+
+mod chain_stats;
+pub use chain_stats::*;
+
+mod chain_volume_limit;
+pub use chain_volume_limit::*;
+
+mod circuit_breaker;
+pub use circuit_breaker::*;
+
+mod epoch_fee_schedule;
+pub use epoch_fee_schedule::*;
+
+mod foreign_contract;
+pub use foreign_contract::*;
+
+mod governance_claim;
+pub use governance_claim::*;
+
+mod multisig_config;
+pub use multisig_config::*;
+
+mod oracle_config;
+pub use oracle_config::*;
+
+mod owner_config;
+pub use owner_config::*;
+
+mod pending_admin_action;
+pub use pending_admin_action::*;
+
+mod pending_multisig_action;
+pub use pending_multisig_action::*;
+
+mod payer_transfer_history;
+pub use payer_transfer_history::*;
+
+mod program_stats;
+pub use program_stats::*;
+
+mod program_version;
+pub use program_version::*;
+
+mod recipient_blacklist;
+pub use recipient_blacklist::*;
+
+mod redeemer_config;
+pub use redeemer_config::*;
+
+mod registered_token;
+pub use registered_token::*;
+
+mod relayer_stats;
+pub use relayer_stats::*;
+
+mod relayer_whitelist;
+pub use relayer_whitelist::*;
+
+mod sender_config;
+pub use sender_config::*;
+
+mod signer_sequence;
+pub use signer_sequence::*;
+
+mod source_chain_allowlist;
+pub use source_chain_allowlist::*;
+
+mod supported_chain;
+pub use supported_chain::*;
+
+mod supported_chains_config;
+pub use supported_chains_config::*;
+
+mod timelock_config;
+pub use timelock_config::*;
+
+mod token_registry;
+pub use token_registry::*;
+
+mod transfer_receipt;
+pub use transfer_receipt::*;
+
+mod wallet_rate_limit;
+pub use wallet_rate_limit::*;
@@ -0,0 +1,20 @@
+use anchor_lang::prelude::*;
+
+/// Singleton PDA, seeded by `[SEED_PREFIX_MULTISIG]`, that governs its own membership through
+/// `PendingMultisigAction` proposals instead of a single owner key. Only the multisig's own
+/// membership (signer list, threshold) is governed this way in this iteration; other owner-only
+/// instructions elsewhere in the program still go through `OwnerConfig`.
+#[account]
+#[derive(Default)]
+pub struct MultisigConfig {
+    pub signers: Vec<Pubkey>,
+    pub threshold: u8,
+    pub enabled: bool,
+    pub pending_action_nonce: u64,
+}
+
+impl MultisigConfig {
+    pub const MAX_SIGNERS: usize = 10;
+
+    pub const MAXIMUM_SIZE: usize = 8 + (4 + 32 * Self::MAX_SIGNERS) + 1 + 1 + 8;
+}
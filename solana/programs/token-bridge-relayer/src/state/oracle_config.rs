@@ -0,0 +1,24 @@
+use anchor_lang::prelude::*;
+
+/// Per-mint Pyth oracle configuration, seeded by `[SEED_PREFIX_ORACLE_CONFIG, mint.key()]`, used
+/// by `update_swap_rate_from_oracle` to validate and convert a Pyth price update into
+/// `RegisteredToken::swap_rate`.
+#[account]
+#[derive(Default)]
+pub struct OracleConfig {
+    /// Pyth `PriceFeed` account this mint's swap rate is read from.
+    pub pyth_feed: Pubkey,
+
+    /// Maximum allowed ratio of the Pyth price's confidence interval to its price, in basis
+    /// points. A wider confidence interval means the oracle is less sure of the price, so
+    /// updates are rejected past this bound rather than writing a possibly-bad rate.
+    pub max_confidence_ratio_bps: u16,
+
+    /// Maximum age, in seconds, of the Pyth price's `publish_time` relative to the current
+    /// on-chain clock. Older prices are rejected as stale.
+    pub max_price_age_seconds: u64,
+}
+
+impl OracleConfig {
+    pub const MAXIMUM_SIZE: usize = 8 + 32 + 2 + 8;
+}
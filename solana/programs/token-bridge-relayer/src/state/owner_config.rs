@@ -0,0 +1,49 @@
+use anchor_lang::prelude::*;
+
+/// Holds the addresses that are allowed to perform routine, non-owner-gated admin actions
+/// (e.g. updating swap rates). `owner` retains sole authority over sensitive instructions
+/// such as ownership transfer.
+#[account]
+#[derive(Default)]
+pub struct OwnerConfig {
+    /// Program's owner.
+    pub owner: Pubkey,
+
+    /// Program's assistant. Can perform admin actions delegated by the owner.
+    pub assistant: Pubkey,
+
+    /// Pending owner, set when an ownership transfer has been proposed but not yet confirmed.
+    pub pending_owner: Option<Pubkey>,
+
+    /// Maximum number of `ForeignContract` registrations allowed at once, bounding the account
+    /// space an owner (or a compromised owner key) can force the program to allocate.
+    pub max_foreign_contracts: u16,
+
+    /// Number of `ForeignContract` accounts currently registered, i.e. not yet closed via
+    /// `close_foreign_contract`.
+    pub registered_contract_count: u16,
+
+    /// Maximum number of `RegisteredToken` registrations allowed at once, bounding the account
+    /// space an owner (or a compromised owner key) can force the program to allocate.
+    pub max_registered_tokens: u16,
+
+    /// Number of `RegisteredToken` accounts currently registered, i.e. not yet closed via
+    /// `deregister_token`.
+    pub registered_token_count: u16,
+}
+
+impl OwnerConfig {
+    pub const MAXIMUM_SIZE: usize = 8 + 32 + 32 + (1 + 32) + 2 + 2 + 2 + 2;
+
+    /// Default value for `max_foreign_contracts`, set by `initialize`.
+    pub const DEFAULT_MAX_FOREIGN_CONTRACTS: u16 = 200;
+
+    /// Default value for `max_registered_tokens`, set by `initialize`.
+    pub const DEFAULT_MAX_REGISTERED_TOKENS: u16 = 512;
+
+    /// Returns whether `key` is authorized to perform assistant-gated admin actions, i.e.
+    /// is either the current owner or the current assistant.
+    pub fn is_authorized(&self, key: &Pubkey) -> bool {
+        self.owner == *key || self.assistant == *key
+    }
+}
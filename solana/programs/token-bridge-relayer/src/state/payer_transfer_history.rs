@@ -0,0 +1,34 @@
+use anchor_lang::prelude::*;
+
+/// Lifetime transfer counters for a single payer, seeded by
+/// `[SEED_PREFIX_PAYER_HISTORY, payer.key()]`, covering both outbound transfers the payer sent
+/// and inbound transfers the payer redeemed (as a relayer). Feeds analytics and any future
+/// volume-based fee tiering; not consulted by any rate limit today.
+#[account]
+#[derive(Default)]
+pub struct PayerTransferHistory {
+    pub total_outbound: u64,
+    pub total_inbound: u64,
+    pub total_volume_bridged: u64,
+    pub first_transfer_slot: u64,
+}
+
+impl PayerTransferHistory {
+    pub const MAXIMUM_SIZE: usize = 8 + 8 + 8 + 8 + 8;
+
+    pub fn record_outbound(&mut self, amount: u64, slot: u64) {
+        if self.first_transfer_slot == 0 {
+            self.first_transfer_slot = slot;
+        }
+        self.total_outbound = self.total_outbound.saturating_add(1);
+        self.total_volume_bridged = self.total_volume_bridged.saturating_add(amount);
+    }
+
+    pub fn record_inbound(&mut self, amount: u64, slot: u64) {
+        if self.first_transfer_slot == 0 {
+            self.first_transfer_slot = slot;
+        }
+        self.total_inbound = self.total_inbound.saturating_add(1);
+        self.total_volume_bridged = self.total_volume_bridged.saturating_add(amount);
+    }
+}
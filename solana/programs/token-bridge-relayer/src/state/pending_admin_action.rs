@@ -0,0 +1,31 @@
+use anchor_lang::prelude::*;
+
+/// Identifies which sensitive instruction a [`PendingAdminAction`] will replay once its timelock
+/// elapses. `encoded_args` is the Borsh-serialized argument list for that instruction.
+pub const ADMIN_ACTION_UPDATE_FEE_RECIPIENT: u8 = 1;
+// 2 was ADMIN_ACTION_UPDATE_SWAP_RATE_PRECISION, retired along with `update_swap_rate_precision`.
+pub const ADMIN_ACTION_CONFIRM_OWNERSHIP_TRANSFER: u8 = 3;
+pub const ADMIN_ACTION_SET_TOKEN_SWAP_RATE_PRECISION: u8 = 4;
+pub const ADMIN_ACTION_UPDATE_PRECISION_AND_FEES: u8 = 5;
+
+/// A sensitive owner-only action that has been proposed but not yet executed, seeded by
+/// `[SEED_PREFIX_PENDING_ACTION, &action_id.to_be_bytes()]`. `execute_*` instructions require
+/// `Clock::get()?.slot >= submitted_slot + TimelockConfig::delay_slots` before replaying
+/// `encoded_args` and require `!executed` so a completed action can't be replayed.
+#[account]
+#[derive(Default)]
+pub struct PendingAdminAction {
+    pub action_id: u64,
+    pub action_type: u8,
+    pub encoded_args: Vec<u8>,
+    pub submitted_slot: u64,
+    pub executed: bool,
+}
+
+impl PendingAdminAction {
+    pub const HEADER_SIZE: usize = 8 + 8 + 1 + 4 + 8 + 1;
+
+    pub fn space_for(encoded_args_len: usize) -> usize {
+        Self::HEADER_SIZE + encoded_args_len
+    }
+}
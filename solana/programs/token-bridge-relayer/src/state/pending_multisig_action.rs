@@ -0,0 +1,32 @@
+use anchor_lang::prelude::*;
+
+/// Identifies which `MultisigConfig` mutation a [`PendingMultisigAction`] will apply once
+/// `approvals` reaches `MultisigConfig::threshold`. `encoded_args` is the Borsh-serialized
+/// argument list for that mutation.
+pub const MULTISIG_ACTION_ADD_SIGNER: u8 = 1;
+pub const MULTISIG_ACTION_REMOVE_SIGNER: u8 = 2;
+pub const MULTISIG_ACTION_SET_THRESHOLD: u8 = 3;
+
+/// A `MultisigConfig` mutation that has been proposed but not yet executed, seeded by
+/// `[SEED_PREFIX_PENDING_MULTISIG_ACTION, &nonce.to_be_bytes()]`. `approve_multisig_action`
+/// appends the caller to `approvals` (each current signer may approve at most once);
+/// `execute_multisig_action` requires `approvals.len() >= MultisigConfig::threshold` and
+/// `!executed` before replaying `encoded_args`.
+#[account]
+#[derive(Default)]
+pub struct PendingMultisigAction {
+    pub nonce: u64,
+    pub action_type: u8,
+    pub encoded_args: Vec<u8>,
+    pub approvals: Vec<Pubkey>,
+    pub executed: bool,
+}
+
+impl PendingMultisigAction {
+    pub const HEADER_SIZE: usize =
+        8 + 8 + 1 + 4 + 4 + (32 * super::MultisigConfig::MAX_SIGNERS) + 1;
+
+    pub fn space_for(encoded_args_len: usize) -> usize {
+        Self::HEADER_SIZE + encoded_args_len
+    }
+}
@@ -0,0 +1,21 @@
+use anchor_lang::prelude::*;
+
+/// Program-wide transfer counters, seeded as a singleton by `[SEED_PREFIX_PROGRAM_STATS]`.
+#[account]
+#[derive(Default)]
+pub struct ProgramStats {
+    pub total_transfers_in: u64,
+    pub total_transfers_out: u64,
+}
+
+impl ProgramStats {
+    pub const MAXIMUM_SIZE: usize = 8 + 8 + 8;
+
+    pub fn record_transfer_in(&mut self) {
+        self.total_transfers_in = self.total_transfers_in.saturating_add(1);
+    }
+
+    pub fn record_transfer_out(&mut self) {
+        self.total_transfers_out = self.total_transfers_out.saturating_add(1);
+    }
+}
@@ -0,0 +1,32 @@
+use anchor_lang::prelude::*;
+
+/// Singleton PDA seeded by `[SEED_PREFIX_PROGRAM_VERSION_CONFIG]` that tracks how many
+/// `ProgramVersion` entries have been appended, so each upgrade record gets a unique, sequential
+/// seed, mirroring `AuditLogConfig::counter`.
+#[account]
+#[derive(Default)]
+pub struct ProgramVersionConfig {
+    pub counter: u64,
+}
+
+impl ProgramVersionConfig {
+    pub const MAXIMUM_SIZE: usize = 8 + 8;
+}
+
+/// Immutable record of which version of the program logic was deployed at a point in time,
+/// seeded by `[SEED_PREFIX_PROGRAM_VERSION, &index.to_be_bytes()]` where `index` is the value of
+/// `ProgramVersionConfig::counter` at the time the entry was created. The entry at
+/// `counter - 1` is the currently deployed version.
+#[account]
+#[derive(Default)]
+pub struct ProgramVersion {
+    pub major: u8,
+    pub minor: u8,
+    pub patch: u8,
+    pub deploy_slot: u64,
+    pub deployer: Pubkey,
+}
+
+impl ProgramVersion {
+    pub const MAXIMUM_SIZE: usize = 8 + 1 + 1 + 1 + 8 + 32;
+}
@@ -0,0 +1,17 @@
+use anchor_lang::prelude::*;
+
+/// Sanctions/compliance entry for a bridge recipient address, seeded by
+/// `[SEED_PREFIX_RECIPIENT_BLACKLIST, address.as_ref()]`. Checked by outbound transfer
+/// instructions only when the caller opts in via `check_blacklist`, since most recipients will
+/// never have an entry and requiring the PDA on every transfer would bloat the default accounts
+/// list.
+#[account]
+#[derive(Default)]
+pub struct RecipientBlacklist {
+    pub address: [u8; 32],
+    pub is_blocked: bool,
+}
+
+impl RecipientBlacklist {
+    pub const MAXIMUM_SIZE: usize = 8 + 32 + 1;
+}
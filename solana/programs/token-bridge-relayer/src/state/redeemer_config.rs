@@ -0,0 +1,58 @@
+use anchor_lang::prelude::*;
+
+/// Config account for inbound (receiving) transfers.
+#[account]
+#[derive(Default)]
+pub struct RedeemerConfig {
+    /// Program's owner.
+    pub owner: Pubkey,
+
+    /// PDA bump.
+    pub bump: u8,
+
+    /// Token Bridge program's relevant addresses.
+    pub token_bridge: InboundTokenBridgeAddresses,
+
+    /// Precision used to calculate the relayer fee.
+    pub relayer_fee_precision: u32,
+
+    /// Whether inbound redemptions are paused. Unlike `SenderConfig.paused`, which only affects
+    /// outbound transfers, this lets the owner halt redemptions during a security incident
+    /// without unregistering every `ForeignContract`.
+    pub inbound_paused: bool,
+
+    /// Recipient of relayer fees earned from completed transfers.
+    pub fee_recipient: Pubkey,
+
+    /// When set, only relayers with a [`crate::state::RelayerWhitelist`] entry marked
+    /// `is_allowed` may submit `complete_*_transfer_with_relay`.
+    pub whitelist_enabled: bool,
+
+    /// Optional second recipient that relayer fees are split with, e.g. a DAO treasury or
+    /// insurance fund. When `None`, `fee_recipient` receives the entire relayer fee regardless
+    /// of `fee_split_bps`.
+    pub secondary_fee_recipient: Option<Pubkey>,
+
+    /// Basis points (out of 10,000) of the relayer fee that go to `fee_recipient`; the
+    /// remainder goes to `secondary_fee_recipient`. Ignored when `secondary_fee_recipient` is
+    /// `None`.
+    pub fee_split_bps: u16,
+}
+
+impl RedeemerConfig {
+    pub const MAXIMUM_SIZE: usize =
+        8 + 32 + 1 + InboundTokenBridgeAddresses::LEN + 4 + 1 + 32 + 1 + (1 + 32) + 2;
+}
+
+#[derive(Debug, AnchorSerialize, AnchorDeserialize, Clone, Default)]
+pub struct InboundTokenBridgeAddresses {
+    pub config: Pubkey,
+    pub wrapped_mint_authority: Pubkey,
+    pub custody_signer: Pubkey,
+    pub mint_authority: Pubkey,
+    pub program: Pubkey,
+}
+
+impl InboundTokenBridgeAddresses {
+    pub const LEN: usize = 32 * 5;
+}
@@ -0,0 +1,324 @@
+use crate::{error::TokenBridgeRelayerError, SWAP_RATE_PRECISION};
+use anchor_lang::prelude::*;
+
+/// Per-mint config account, seeded by `[SEED_PREFIX_REGISTERED_TOKEN, mint.key()]`, that tracks
+/// the swap rate and native swap cap used when accepting `to_native_token_amount` for a given
+/// SPL token.
+#[account]
+#[derive(Default)]
+pub struct RegisteredToken {
+    /// Swap rate for this token, scaled by `SWAP_RATE_PRECISION`. Represents the price of one
+    /// whole token in USD.
+    pub swap_rate: u64,
+
+    /// Maximum amount of native SOL (in lamports) that can be swapped for this token in a
+    /// single transfer.
+    pub max_native_swap_amount: u64,
+
+    /// Slot at which `swap_rate` was last updated, used to detect stale prices.
+    pub last_swap_rate_update: u64,
+
+    /// Cumulative amount of this token bridged in through the relayer, in raw mint units.
+    pub cumulative_volume_in: u64,
+
+    /// Cumulative amount of this token bridged out through the relayer, in raw mint units.
+    pub cumulative_volume_out: u64,
+
+    /// Cached `Mint::decimals`, captured at registration time so transfer instructions don't
+    /// need to load the full `Mint` account just to read a value that never changes.
+    pub decimals: u8,
+
+    /// Whether native swaps are accepted for this token. Disabling this is the supported way to
+    /// turn off swaps for a token (e.g. a stablecoin where a 1:1 rate makes swapping pointless),
+    /// rather than the fragile workaround of setting `max_native_swap_amount` to zero.
+    pub swap_enabled: bool,
+
+    /// Maximum amount of this token, normalized to Token Bridge's 8-decimal precision, that can
+    /// be bridged out in a single transfer. Zero means uncapped.
+    pub max_transfer_amount: u64,
+
+    /// Cap, in basis points of the normalized transfer amount, on how much of a transfer's value
+    /// the relayer fee may consume. `0` means uncapped. Protects small transfers from a flat USD
+    /// fee eating a disproportionate share of the bridged value.
+    pub max_fee_bps: u16,
+
+    /// Schema version of this account's data. `0` means the account predates this field and was
+    /// allocated at the old, smaller `MAXIMUM_SIZE`; `migrate_registered_token` reallocs it to
+    /// version `1`. `1` means the account still carries the removed `is_registered` field;
+    /// `migrate_registered_token_v2` rewrites it straight to `CURRENT_VERSION`, dropping that
+    /// field and adding whatever fields have been added since. `2` means the account predates
+    /// `swap_rate_precision_override`/`max_native_swap_per_tx` and is still at the smaller,
+    /// pre-those-fields size; `migrate_registered_token_v3` reallocs it up to `3`, the current
+    /// layout. Accounts created by `register_token` today start at `CURRENT_VERSION` directly.
+    pub version: u8,
+
+    /// Overrides [`crate::SWAP_RATE_PRECISION`] for this token's `swap_rate` scaling. `0` means
+    /// use the global precision. Set via the timelocked
+    /// `propose_set_token_swap_rate_precision`/`execute_set_token_swap_rate_precision` pair for
+    /// tokens (e.g. rebasing tokens) whose exchange rate needs more precision than the global
+    /// constant provides.
+    pub swap_rate_precision_override: u32,
+
+    /// Further caps a single transfer's native swap below `max_native_swap_amount`, to limit
+    /// per-transaction risk independently of the program-wide max. `0` means uncapped, i.e. only
+    /// `max_native_swap_amount` applies. Set via `update_max_native_swap_per_tx`.
+    pub max_native_swap_per_tx: u64,
+}
+
+impl RegisteredToken {
+    pub const MAXIMUM_SIZE: usize = 8 + 8 + 8 + 8 + 8 + 8 + 1 + 1 + 8 + 2 + 1 + 4 + 8;
+
+    /// Schema version written by `register_token` for newly created accounts, and the target
+    /// version `migrate_registered_token`/`migrate_registered_token_v2`/
+    /// `migrate_registered_token_v3` bring pre-existing accounts up to.
+    pub const CURRENT_VERSION: u8 = 3;
+
+    pub fn new(
+        swap_rate: u64,
+        max_native_swap_amount: u64,
+        decimals: u8,
+        enable_swap: bool,
+        slot: u64,
+    ) -> Self {
+        Self {
+            swap_rate,
+            max_native_swap_amount,
+            last_swap_rate_update: slot,
+            cumulative_volume_in: 0,
+            cumulative_volume_out: 0,
+            decimals,
+            swap_enabled: enable_swap,
+            max_transfer_amount: 0,
+            max_fee_bps: 0,
+            version: Self::CURRENT_VERSION,
+            swap_rate_precision_override: 0,
+            max_native_swap_per_tx: 0,
+        }
+    }
+
+    /// Lowest `swap_rate` `register_token`/`update_swap_rate` accept, relative to the same
+    /// `swap_rate_precision` `swap_rate` is itself scaled by (0.1% of it) — i.e.
+    /// [`Self::effective_swap_rate_precision`], not `relayer_fee_precision`, which is an
+    /// unrelated, independently-configured scale. A `swap_rate` set too close to zero makes
+    /// `ForeignContract::checked_usd_to_token_amount`'s division blow up the resulting token fee
+    /// past any transfer amount a user could plausibly pay, so both entry points reject a rate
+    /// below this floor outright instead of letting a fat-fingered value silently price transfers
+    /// out of existence.
+    pub fn min_valid_swap_rate(swap_rate_precision: u64) -> u64 {
+        swap_rate_precision / 1000
+    }
+
+    /// Returns `swap_rate_precision_override` if set, else [`SWAP_RATE_PRECISION`]. This is the
+    /// value every call site should pass as `calculate_native_swap_amounts`'s
+    /// `swap_rate_precision` argument.
+    pub fn effective_swap_rate_precision(&self) -> u64 {
+        if self.swap_rate_precision_override > 0 {
+            self.swap_rate_precision_override as u64
+        } else {
+            SWAP_RATE_PRECISION
+        }
+    }
+
+    /// Adds `amount` to `cumulative_volume_in`, saturating instead of aborting the transfer if
+    /// the counter would overflow.
+    pub fn record_volume_in(&mut self, amount: u64) {
+        self.cumulative_volume_in = self.cumulative_volume_in.saturating_add(amount);
+    }
+
+    /// Adds `amount` to `cumulative_volume_out`, saturating instead of aborting the transfer if
+    /// the counter would overflow.
+    pub fn record_volume_out(&mut self, amount: u64) {
+        self.cumulative_volume_out = self.cumulative_volume_out.saturating_add(amount);
+    }
+
+    /// Converts `to_native_token_amount` (raw units of this mint, already capped by
+    /// `max_native_swap_amount`) into the lamports the relayer should front the recipient, using
+    /// `swap_rate` as this token's price. Returns `(token_amount_in, native_amount_out)`.
+    ///
+    /// `swap_rate_precision` is taken as an explicit parameter (every current call site passes
+    /// [`crate::SWAP_RATE_PRECISION`]) rather than divided by internally, so the scaling
+    /// assumption behind `swap_rate` is visible at every call site instead of hidden inside this
+    /// function, and a caller can't silently divide by zero if that assumption is ever wrong.
+    ///
+    /// The `u128` multiplication and both divisions are `checked_*`, so an overflow here (e.g.
+    /// from a `swap_rate` far outside [`Self::min_valid_swap_rate`]'s floor) surfaces as
+    /// `InsufficientFunds` rather than panicking or wrapping.
+    ///
+    /// Scale-invariant in `swap_rate_precision`: doubling both `swap_rate_precision` and
+    /// `swap_rate` together leaves `native_amount_out` unchanged, since the two appear as a
+    /// ratio in the final `checked_div`. See the `scale_invariant_in_swap_rate_precision` test
+    /// below.
+    pub fn calculate_native_swap_amounts(
+        &self,
+        to_native_token_amount: u64,
+        decimals: u8,
+        swap_rate_precision: u64,
+    ) -> Result<(u64, u64)> {
+        require!(
+            swap_rate_precision > 0,
+            TokenBridgeRelayerError::InvalidSwapRatePrecision
+        );
+
+        let mut token_amount_in = to_native_token_amount.min(self.max_native_swap_amount);
+        if self.max_native_swap_per_tx > 0 {
+            token_amount_in = token_amount_in.min(self.max_native_swap_per_tx);
+        }
+        if token_amount_in == 0 {
+            return Ok((0, 0));
+        }
+
+        let native_amount_out = (token_amount_in as u128)
+            .checked_mul(self.swap_rate as u128)
+            .and_then(|v| v.checked_div(10u128.pow(decimals as u32)))
+            .and_then(|v| v.checked_div(swap_rate_precision as u128))
+            .ok_or(TokenBridgeRelayerError::InsufficientFunds)?;
+
+        Ok((
+            token_amount_in,
+            u64::try_from(native_amount_out)
+                .map_err(|_| TokenBridgeRelayerError::InsufficientFunds)?,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn token(swap_rate: u64, max_native_swap_amount: u64, decimals: u8) -> RegisteredToken {
+        RegisteredToken::new(swap_rate, max_native_swap_amount, decimals, true, 0)
+    }
+
+    #[test]
+    fn calculate_native_swap_amounts_basic() {
+        // swap_rate half of SWAP_RATE_PRECISION means a price of 0.5 native units per token.
+        let t = token(SWAP_RATE_PRECISION / 2, u64::MAX, 6);
+        let (token_amount_in, native_amount_out) = t
+            .calculate_native_swap_amounts(1_000_000_000, 6, SWAP_RATE_PRECISION)
+            .unwrap();
+        assert_eq!(token_amount_in, 1_000_000_000);
+        assert_eq!(native_amount_out, 500);
+    }
+
+    #[test]
+    fn calculate_native_swap_amounts_caps_at_max_native_swap_amount() {
+        let t = token(SWAP_RATE_PRECISION, 100, 6);
+        let (token_amount_in, _) = t
+            .calculate_native_swap_amounts(1_000_000, 6, SWAP_RATE_PRECISION)
+            .unwrap();
+        assert_eq!(token_amount_in, 100);
+    }
+
+    #[test]
+    fn calculate_native_swap_amounts_zero_swap_rate_yields_zero_native_amount() {
+        let t = token(0, u64::MAX, 6);
+        let (token_amount_in, native_amount_out) = t
+            .calculate_native_swap_amounts(1_000_000, 6, SWAP_RATE_PRECISION)
+            .unwrap();
+        assert_eq!(token_amount_in, 1_000_000);
+        assert_eq!(native_amount_out, 0);
+    }
+
+    #[test]
+    fn calculate_native_swap_amounts_zero_precision_errors() {
+        let t = token(SWAP_RATE_PRECISION, u64::MAX, 6);
+        assert!(t.calculate_native_swap_amounts(1_000_000, 6, 0).is_err());
+    }
+
+    #[test]
+    fn scale_invariant_in_swap_rate_precision() {
+        for &precision in &[1_000_000u64, SWAP_RATE_PRECISION, 10_000_000_000] {
+            let a = token(3 * precision / 2, u64::MAX, 6)
+                .calculate_native_swap_amounts(1_000_000, 6, precision)
+                .unwrap();
+            let b = token(3 * precision, u64::MAX, 6)
+                .calculate_native_swap_amounts(1_000_000, 6, 2 * precision)
+                .unwrap();
+            assert_eq!(a, b, "doubling swap_rate and swap_rate_precision together should be a no-op, precision={precision}");
+        }
+    }
+
+    #[test]
+    fn min_valid_swap_rate_scales_with_given_precision() {
+        assert_eq!(
+            RegisteredToken::min_valid_swap_rate(SWAP_RATE_PRECISION),
+            SWAP_RATE_PRECISION / 1000
+        );
+        assert_eq!(RegisteredToken::min_valid_swap_rate(1_000), 1);
+    }
+
+    #[test]
+    fn effective_swap_rate_precision_falls_back_to_global_constant() {
+        let mut t = token(SWAP_RATE_PRECISION, u64::MAX, 6);
+        assert_eq!(t.effective_swap_rate_precision(), SWAP_RATE_PRECISION);
+
+        t.swap_rate_precision_override = 1_000_000;
+        assert_eq!(t.effective_swap_rate_precision(), 1_000_000);
+    }
+}
+
+/// Byte-for-byte layout of `RegisteredToken` before the `version` field was added, used only to
+/// read a pre-`migrate_registered_token` account with the field offsets it was actually written
+/// with.
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub(crate) struct RegisteredTokenV0 {
+    pub is_registered: bool,
+    pub swap_rate: u64,
+    pub max_native_swap_amount: u64,
+    pub last_swap_rate_update: u64,
+    pub cumulative_volume_in: u64,
+    pub cumulative_volume_out: u64,
+    pub decimals: u8,
+    pub swap_enabled: bool,
+    pub max_transfer_amount: u64,
+    pub max_fee_bps: u16,
+}
+
+impl RegisteredTokenV0 {
+    pub(crate) const SIZE: usize = 8 + 1 + 8 + 8 + 8 + 8 + 8 + 1 + 1 + 8 + 2;
+}
+
+/// Byte-for-byte layout of `RegisteredToken` between the `version` field being added and the
+/// `is_registered` field being removed, used only to read a `migrate_registered_token`-ed (but
+/// not yet `migrate_registered_token_v2`-ed) account with the field offsets it was actually
+/// written with.
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub(crate) struct RegisteredTokenV1 {
+    pub is_registered: bool,
+    pub swap_rate: u64,
+    pub max_native_swap_amount: u64,
+    pub last_swap_rate_update: u64,
+    pub cumulative_volume_in: u64,
+    pub cumulative_volume_out: u64,
+    pub decimals: u8,
+    pub swap_enabled: bool,
+    pub max_transfer_amount: u64,
+    pub max_fee_bps: u16,
+    pub version: u8,
+}
+
+impl RegisteredTokenV1 {
+    pub(crate) const SIZE: usize = RegisteredTokenV0::SIZE + 1;
+}
+
+/// Byte-for-byte layout of `RegisteredToken` between the `is_registered` field being removed and
+/// `swap_rate_precision_override`/`max_native_swap_per_tx` being added, used only to read a
+/// `migrate_registered_token_v2`-ed (but not yet `migrate_registered_token_v3`-ed) account with
+/// the field offsets it was actually written with.
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub(crate) struct RegisteredTokenV2 {
+    pub swap_rate: u64,
+    pub max_native_swap_amount: u64,
+    pub last_swap_rate_update: u64,
+    pub cumulative_volume_in: u64,
+    pub cumulative_volume_out: u64,
+    pub decimals: u8,
+    pub swap_enabled: bool,
+    pub max_transfer_amount: u64,
+    pub max_fee_bps: u16,
+    pub version: u8,
+}
+
+impl RegisteredTokenV2 {
+    pub(crate) const SIZE: usize = RegisteredTokenV1::SIZE - 1;
+}
@@ -0,0 +1,25 @@
+use anchor_lang::prelude::*;
+
+/// Per-relayer earnings counters, seeded by `[SEED_PREFIX_RELAYER_STATS, relayer.key()]`, so a
+/// relayer can measure its own performance in the fee market without an off-chain indexer.
+#[account]
+#[derive(Default)]
+pub struct RelayerStats {
+    pub total_transfers: u64,
+    pub total_tokens_earned: u64,
+    /// Native SOL moved to facilitate swaps on redemptions this relayer completed. Reflects
+    /// swap-facilitation volume, not direct income to the relayer.
+    pub total_native_earned: u64,
+    pub last_transfer_slot: u64,
+}
+
+impl RelayerStats {
+    pub const MAXIMUM_SIZE: usize = 8 + 8 + 8 + 8 + 8;
+
+    pub fn record_earnings(&mut self, tokens_earned: u64, native_earned: u64, slot: u64) {
+        self.total_transfers = self.total_transfers.saturating_add(1);
+        self.total_tokens_earned = self.total_tokens_earned.saturating_add(tokens_earned);
+        self.total_native_earned = self.total_native_earned.saturating_add(native_earned);
+        self.last_transfer_slot = slot;
+    }
+}
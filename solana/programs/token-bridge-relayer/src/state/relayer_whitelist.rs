@@ -0,0 +1,15 @@
+use anchor_lang::prelude::*;
+
+/// Whitelist entry for a relayer permitted to submit `complete_*_transfer_with_relay`
+/// while `RedeemerConfig::whitelist_enabled` is set. Seeded by
+/// `[SEED_PREFIX_RELAYER_WHITELIST, relayer.as_ref()]`.
+#[account]
+#[derive(Default)]
+pub struct RelayerWhitelist {
+    pub relayer: Pubkey,
+    pub is_allowed: bool,
+}
+
+impl RelayerWhitelist {
+    pub const MAXIMUM_SIZE: usize = 8 + 32 + 1;
+}
@@ -0,0 +1,67 @@
+use anchor_lang::prelude::*;
+
+/// Config account for outbound (sending) transfers.
+#[account]
+#[derive(Default)]
+pub struct SenderConfig {
+    /// Program's owner.
+    pub owner: Pubkey,
+
+    /// PDA bump.
+    pub bump: u8,
+
+    /// Token Bridge program's relevant addresses.
+    pub token_bridge: OutboundTokenBridgeAddresses,
+
+    /// Whether outbound transfers are paused.
+    pub paused: bool,
+
+    /// Precision used to calculate the relayer fee.
+    pub relayer_fee_precision: u32,
+
+    /// Maximum age, in slots, a registered token's swap rate is allowed to have before
+    /// transfers that rely on it are rejected. `0` disables the staleness check.
+    pub max_swap_rate_age_slots: u64,
+
+    /// Length, in slots, of the rolling window used to rate-limit outbound transfers per
+    /// wallet. `0` disables the per-wallet rate limit.
+    pub rate_limit_window_slots: u64,
+
+    /// Maximum normalized (8-decimal) amount a single wallet may transfer out within
+    /// `rate_limit_window_slots`. Only enforced when `rate_limit_window_slots > 0`.
+    pub rate_limit_max_amount: u64,
+
+    /// Normalized (8-decimal) transfer amount above which `LargeTransferWarning` is emitted for
+    /// off-chain monitoring. `0` disables the warning; it never blocks the transfer.
+    pub large_transfer_threshold: u64,
+
+    /// Cached lamport cost of the Wormhole core bridge message fee (`BridgeData::config::fee` in
+    /// a real deployment), used by the pre-check in the transfer-out instructions so a payer with
+    /// insufficient lamports is rejected before any tokens move, rather than after. Kept as a
+    /// cached value updated via `update_wormhole_message_fee` rather than CPI-read live, since
+    /// this fee changes rarely and a live read would require depending on the Wormhole core
+    /// bridge program.
+    pub wormhole_message_fee: u64,
+}
+
+impl SenderConfig {
+    pub const MAXIMUM_SIZE: usize =
+        8 + 32 + 1 + OutboundTokenBridgeAddresses::LEN + 1 + 4 + 8 + 8 + 8 + 8 + 8;
+}
+
+#[derive(Debug, AnchorSerialize, AnchorDeserialize, Clone, Default)]
+pub struct OutboundTokenBridgeAddresses {
+    pub config: Pubkey,
+    pub authority_signer: Pubkey,
+    pub custody_signer: Pubkey,
+    pub wormhole_bridge: Pubkey,
+    pub emitter: Pubkey,
+    pub wormhole_fee_collector: Pubkey,
+    pub sequence: Pubkey,
+    pub sender: Pubkey,
+    pub program: Pubkey,
+}
+
+impl OutboundTokenBridgeAddresses {
+    pub const LEN: usize = 32 * 9;
+}
@@ -0,0 +1,25 @@
+use anchor_lang::prelude::*;
+
+/// Tracks a monotonically increasing sequence number per payer, used to derive a unique
+/// `tmp_token_account` seed for concurrent outbound transfers from the same wallet.
+#[account]
+#[derive(Default)]
+pub struct SignerSequence {
+    /// Note: nothing in this program encodes `value` into a PDA seed (or any other byte buffer)
+    /// today, so there is no `to_be_bytes`/`to_le_bytes` ordering choice to document here. Seeds
+    /// that need per-payer uniqueness (e.g. `tmp_token_account`'s) are derived from the mint and
+    /// payer keys instead; if a future instruction does need to seed off this value, pick
+    /// `to_be_bytes()` for consistency with `ForeignContract`'s `chain.to_be_bytes()` seed.
+    pub value: u64,
+
+    /// Value of `value` as of the last outbound transfer that completed its Token Bridge CPI
+    /// successfully. If a transfer instruction fails after `value` is upticked but before the
+    /// CPI lands (e.g. the `tmp_token_account` transfer fails), `value` and
+    /// `last_committed_sequence` diverge, which `detect_sequence_gap` treats as a signal that a
+    /// transfer was skipped.
+    pub last_committed_sequence: u64,
+}
+
+impl SignerSequence {
+    pub const MAXIMUM_SIZE: usize = 8 + 8 + 8;
+}
@@ -0,0 +1,14 @@
+use anchor_lang::prelude::*;
+
+/// Singleton toggle for inbound source-chain filtering, seeded by `[SEED_PREFIX_SOURCE_ALLOWLIST]`.
+/// While `enabled`, `complete_native_transfer_with_relay` and `complete_wrapped_transfer_with_relay`
+/// require an [`super::AllowedSourceChain`] marker for the VAA's origin chain.
+#[account]
+#[derive(Default)]
+pub struct SourceChainAllowlist {
+    pub enabled: bool,
+}
+
+impl SourceChainAllowlist {
+    pub const MAXIMUM_SIZE: usize = 8 + 1;
+}
@@ -0,0 +1,15 @@
+use anchor_lang::prelude::*;
+
+/// Marker PDA, seeded by `[SEED_PREFIX_SUPPORTED_CHAIN, &chain.to_be_bytes()]`, whose existence
+/// means outbound transfers to `chain` are allowed while [`super::SupportedChainsConfig`] has
+/// `enforce_allowlist` set. Distinct from registering a [`super::ForeignContract`]: a chain can
+/// have a foreign contract on file without a guardian set actually attesting for it yet.
+#[account]
+#[derive(Default)]
+pub struct SupportedChain {
+    pub chain: u16,
+}
+
+impl SupportedChain {
+    pub const MAXIMUM_SIZE: usize = 8 + 2;
+}
@@ -0,0 +1,15 @@
+use anchor_lang::prelude::*;
+
+/// Singleton toggle for outbound recipient-chain filtering, seeded by
+/// `[SEED_PREFIX_SUPPORTED_CHAINS_CONFIG]`. While `enforce_allowlist` is true,
+/// `transfer_native_tokens_with_relay` and `transfer_wrapped_tokens_with_relay` require a
+/// [`super::SupportedChain`] marker for `recipient_chain`.
+#[account]
+#[derive(Default)]
+pub struct SupportedChainsConfig {
+    pub enforce_allowlist: bool,
+}
+
+impl SupportedChainsConfig {
+    pub const MAXIMUM_SIZE: usize = 8 + 1;
+}
@@ -0,0 +1,15 @@
+use anchor_lang::prelude::*;
+
+/// Singleton PDA seeded by `[SEED_PREFIX_TIMELOCK_CONFIG]`. Governs how long a
+/// [`super::PendingAdminAction`] must sit before it can be executed, and allocates the sequential
+/// `action_id` seed for each newly proposed action, mirroring [`super::AuditLogConfig::counter`].
+#[account]
+#[derive(Default)]
+pub struct TimelockConfig {
+    pub delay_slots: u64,
+    pub next_action_id: u64,
+}
+
+impl TimelockConfig {
+    pub const MAXIMUM_SIZE: usize = 8 + 8 + 8;
+}
@@ -0,0 +1,20 @@
+use anchor_lang::prelude::*;
+
+/// Singleton registry of every mint that has an active [`crate::state::RegisteredToken`],
+/// seeded by `[b"token_registry"]`. Lets off-chain relayer software discover registered tokens
+/// without scanning all program accounts. Grown and shrunk in place via `AccountInfo::realloc`
+/// as tokens are registered and deregistered.
+#[account]
+#[derive(Default)]
+pub struct TokenRegistry {
+    pub mints: Vec<Pubkey>,
+}
+
+impl TokenRegistry {
+    /// Anchor's 8-byte account discriminator plus the 4-byte `Vec` length prefix.
+    pub const HEADER_SIZE: usize = 8 + 4;
+
+    pub fn space_for(mint_count: usize) -> usize {
+        Self::HEADER_SIZE + mint_count * 32
+    }
+}
@@ -0,0 +1,20 @@
+use anchor_lang::prelude::*;
+
+/// Immutable record of a completed redemption, seeded by `[SEED_PREFIX_RECEIPT, &vaa_hash]`, so
+/// users and off-chain indexers can look up whether a given VAA has been redeemed by this
+/// program without replaying `complete_native_transfer_with_relay` /
+/// `complete_wrapped_transfer_with_relay`. Never written to after creation.
+#[account]
+#[derive(Default)]
+pub struct TransferReceipt {
+    pub redeemed_at_slot: u64,
+    pub redeemer: Pubkey,
+    pub recipient: Pubkey,
+    pub token_amount: u64,
+    pub native_swap_out: u64,
+    pub fee_paid: u64,
+}
+
+impl TransferReceipt {
+    pub const MAXIMUM_SIZE: usize = 8 + 8 + 32 + 32 + 8 + 8 + 8;
+}
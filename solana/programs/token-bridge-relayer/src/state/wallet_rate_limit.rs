@@ -0,0 +1,33 @@
+use anchor_lang::prelude::*;
+
+/// Rolling-window transfer tracker for a single wallet, seeded by
+/// `[SEED_PREFIX_WALLET_RATE_LIMIT, wallet.as_ref()]`.
+#[account]
+#[derive(Default)]
+pub struct WalletRateLimit {
+    pub wallet: Pubkey,
+    pub window_start_slot: u64,
+    pub amount_in_window: u64,
+}
+
+impl WalletRateLimit {
+    pub const MAXIMUM_SIZE: usize = 8 + 32 + 8 + 8;
+
+    /// Records `normalized_amount` against the window starting at `current_slot`, resetting
+    /// the window first if it has expired. Returns the new cumulative amount in the window.
+    pub fn record(
+        &mut self,
+        wallet: Pubkey,
+        current_slot: u64,
+        window_slots: u64,
+        normalized_amount: u64,
+    ) -> u64 {
+        self.wallet = wallet;
+        if current_slot.saturating_sub(self.window_start_slot) >= window_slots {
+            self.window_start_slot = current_slot;
+            self.amount_in_window = 0;
+        }
+        self.amount_in_window = self.amount_in_window.saturating_add(normalized_amount);
+        self.amount_in_window
+    }
+}
@@ -0,0 +1,125 @@
+use crate::{
+    constants::WormholeEvmChainId, error::TokenBridgeRelayerError, TOKEN_BRIDGE_PROGRAM_ID,
+    WORMHOLE_CORE_BRIDGE_PROGRAM_ID,
+};
+use anchor_lang::prelude::*;
+
+/// Returns whether `address` is one of the well-known program addresses a `TransferWithRelay`
+/// recipient must never name: the System Program, the Token Bridge program, the Wormhole core
+/// bridge program, or this program itself. None of these can legitimately hold an SPL token
+/// account, so naming one as a recipient would either be malformed input or an attempt to route
+/// a transfer back into the bridge infrastructure instead of an end-user wallet.
+fn is_reserved_program_address(address: &Pubkey) -> bool {
+    *address == System::id()
+        || *address == TOKEN_BRIDGE_PROGRAM_ID
+        || *address == WORMHOLE_CORE_BRIDGE_PROGRAM_ID
+        || *address == crate::ID
+}
+
+/// Returns whether `recipient` is usable as a `TransferWithRelay` message's recipient address:
+/// nonzero and not one of [`is_reserved_program_address`]'s reserved program addresses.
+pub fn valid_recipient(recipient: &[u8; 32]) -> bool {
+    if *recipient == [0u8; 32] {
+        return false;
+    }
+
+    !is_reserved_program_address(&Pubkey::from(*recipient))
+}
+
+/// Returns whether `address` is usable as a [`crate::state::ForeignContract`]'s emitter address:
+/// nonzero and not one of [`is_reserved_program_address`]'s reserved program addresses. A foreign
+/// contract registered as one of those addresses could never emit a legitimate Token Bridge
+/// transfer, so registering it is always a configuration mistake.
+pub fn valid_foreign_contract_address(address: &[u8; 32]) -> bool {
+    if *address == [0u8; 32] {
+        return false;
+    }
+
+    !is_reserved_program_address(&Pubkey::from(*address))
+}
+
+/// Returns whether `chain` is one of Wormhole's EVM-compatible chains, per
+/// [`WormholeEvmChainId`].
+fn is_evm_chain(chain: u16) -> bool {
+    matches!(
+        chain,
+        WormholeEvmChainId::ETHEREUM
+            | WormholeEvmChainId::BSC
+            | WormholeEvmChainId::POLYGON
+            | WormholeEvmChainId::AVALANCHE
+            | WormholeEvmChainId::FANTOM
+            | WormholeEvmChainId::CELO
+            | WormholeEvmChainId::ARBITRUM
+            | WormholeEvmChainId::OPTIMISM
+    )
+}
+
+/// For a [`WormholeEvmChainId`] destination, an EVM address is only 20 bytes, so Wormhole's
+/// 32-byte `recipient_address` must have those 20 bytes right-aligned with the leading 12 bytes
+/// zeroed. Non-EVM chains use the full 32 bytes for their native address format and skip this
+/// check.
+pub fn validate_evm_recipient(chain: u16, recipient_address: &[u8; 32]) -> Result<()> {
+    if is_evm_chain(chain) {
+        require!(
+            recipient_address[..12] == [0u8; 12],
+            TokenBridgeRelayerError::InvalidEvmRecipientFormat
+        );
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A well-formed EVM address, right-aligned in the 32-byte Wormhole address format with the
+    /// leading 12 bytes zeroed, and not one of the reserved program addresses.
+    fn evm_address() -> [u8; 32] {
+        let mut address = [0u8; 32];
+        address[12..].copy_from_slice(&[0x11u8; 20]);
+        address
+    }
+
+    #[test]
+    fn valid_recipient_accepts_legitimate_evm_address() {
+        assert!(valid_recipient(&evm_address()));
+    }
+
+    #[test]
+    fn valid_recipient_rejects_zero_address() {
+        assert!(!valid_recipient(&[0u8; 32]));
+    }
+
+    #[test]
+    fn valid_recipient_rejects_reserved_program_addresses() {
+        assert!(!valid_recipient(&System::id().to_bytes()));
+        assert!(!valid_recipient(&TOKEN_BRIDGE_PROGRAM_ID.to_bytes()));
+        assert!(!valid_recipient(
+            &WORMHOLE_CORE_BRIDGE_PROGRAM_ID.to_bytes()
+        ));
+        assert!(!valid_recipient(&crate::ID.to_bytes()));
+    }
+
+    #[test]
+    fn valid_foreign_contract_address_accepts_legitimate_evm_address() {
+        assert!(valid_foreign_contract_address(&evm_address()));
+    }
+
+    #[test]
+    fn valid_foreign_contract_address_rejects_zero_address() {
+        assert!(!valid_foreign_contract_address(&[0u8; 32]));
+    }
+
+    #[test]
+    fn valid_foreign_contract_address_rejects_reserved_program_addresses() {
+        assert!(!valid_foreign_contract_address(&System::id().to_bytes()));
+        assert!(!valid_foreign_contract_address(
+            &TOKEN_BRIDGE_PROGRAM_ID.to_bytes()
+        ));
+        assert!(!valid_foreign_contract_address(
+            &WORMHOLE_CORE_BRIDGE_PROGRAM_ID.to_bytes()
+        ));
+        assert!(!valid_foreign_contract_address(&crate::ID.to_bytes()));
+    }
+}